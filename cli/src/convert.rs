@@ -0,0 +1,15 @@
+use std::path::Path;
+
+use tent::convert;
+
+/// Converts an html document to a template, or a `.css` stylesheet to a `css!` one.
+pub fn convert(path: &Path) -> Result<String, String> {
+    let source = std::fs::read_to_string(path)
+        .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+    let res = if path.extension().is_some_and(|ext| ext == "css") {
+        convert::css(&source)
+    } else {
+        convert::html(&source)
+    };
+    res.map_err(|e| format!("cannot convert {}: {}", path.display(), e))
+}
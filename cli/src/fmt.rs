@@ -0,0 +1,63 @@
+use std::path::{Path, PathBuf};
+
+use tent::format;
+
+/// Templates, stylesheets and Rust files under `path`, or `path` itself when it is a file.
+fn files(path: &Path, res: &mut Vec<PathBuf>) -> Result<(), String> {
+    if !path.is_dir() {
+        res.push(path.to_path_buf());
+        return Ok(());
+    }
+    let entries =
+        std::fs::read_dir(path).map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+    for entry in entries {
+        let path = entry.map_err(|e| e.to_string())?.path();
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        if name.starts_with('.') || name == "target" {
+            continue;
+        }
+        if path.is_dir() || name.ends_with(".tent") || name.ends_with(".rs") {
+            files(&path, res)?;
+        }
+    }
+    Ok(())
+}
+
+fn formatted(path: &Path, source: &str) -> String {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    if name.ends_with(".css.tent") {
+        format::css(source) + "\n"
+    } else if name.ends_with(".tent") {
+        format::html(source) + "\n"
+    } else {
+        format::rust(source)
+    }
+}
+
+/// Formats the files in place, or with `check` only lists those that aren't formatted.
+pub fn fmt(paths: &[PathBuf], check: bool) -> Result<(), String> {
+    let mut found = vec![];
+    for path in paths {
+        files(path, &mut found)?;
+    }
+    let mut unformatted = vec![];
+    for path in found {
+        let source = std::fs::read_to_string(&path)
+            .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+        let formatted = formatted(&path, &source);
+        if formatted == source {
+            continue;
+        }
+        if check {
+            unformatted.push(path.display().to_string());
+        } else {
+            std::fs::write(&path, formatted)
+                .map_err(|e| format!("cannot write {}: {}", path.display(), e))?;
+        }
+    }
+    if unformatted.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("not formatted:\n{}", unformatted.join("\n")))
+    }
+}
@@ -0,0 +1,72 @@
+mod convert;
+mod fmt;
+mod render;
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage:
+    tent render <templates> <out> [--data <file>]
+    tent fmt [--check] <paths>...
+    tent convert <file>
+
+Commands:
+    render    Renders the `.tent` templates of a directory to `.html`, and its `.css.tent`
+              stylesheets to `.css`. Files starting with `_`, like layouts, are skipped.
+              Values of a JSON or TOML data file are given to every template, and the
+              values under `pages.<name>` only to the template of that name.
+    fmt       Formats `.tent` files, and the `html!` and `css!` templates of `.rs` files,
+              in place. Directories are searched recursively. With `--check`, lists the
+              files that aren't formatted instead.
+    convert   Prints an html document as a `.tent` template, or a `.css` stylesheet as a
+              `.css.tent` one.";
+
+fn run(args: &[String]) -> Result<(), String> {
+    match args {
+        [command, rest @ ..] if command == "render" => {
+            let mut paths = vec![];
+            let mut data = None;
+            let mut rest = rest.iter();
+            while let Some(arg) = rest.next() {
+                if arg == "--data" {
+                    data = Some(PathBuf::from(rest.next().ok_or("`--data` needs a file")?));
+                } else {
+                    paths.push(PathBuf::from(arg));
+                }
+            }
+            match paths.as_slice() {
+                [templates, out] => render::render(templates, out, data.as_deref()),
+                _ => Err(USAGE.to_string()),
+            }
+        }
+        [command, rest @ ..] if command == "fmt" => {
+            let check = rest.iter().any(|arg| arg == "--check");
+            let paths = rest
+                .iter()
+                .filter(|arg| *arg != "--check")
+                .map(PathBuf::from)
+                .collect::<Vec<_>>();
+            if paths.is_empty() {
+                return Err(USAGE.to_string());
+            }
+            fmt::fmt(&paths, check)
+        }
+        [command, file] if command == "convert" => {
+            println!("{}", convert::convert(file.as_ref())?);
+            Ok(())
+        }
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
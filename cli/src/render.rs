@@ -0,0 +1,113 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use tent::runtime::{Context, Stylesheet, Template, Value};
+
+const TEMPLATE: &str = ".tent";
+const STYLESHEET: &str = ".css.tent";
+
+fn value(json: serde_json::Value) -> Value {
+    match json {
+        serde_json::Value::Null => Value::Text(String::new()),
+        serde_json::Value::String(text) => Value::Text(text),
+        serde_json::Value::Array(values) => Value::List(values.into_iter().map(value).collect()),
+        serde_json::Value::Object(values) => Value::Map(
+            values
+                .into_iter()
+                .map(|(key, json)| (key, value(json)))
+                .collect::<BTreeMap<_, _>>(),
+        ),
+        other => Value::Text(other.to_string()),
+    }
+}
+
+/// Reads a JSON or TOML data file, as told by its extension.
+fn read_data(path: &Path) -> Result<BTreeMap<String, Value>, String> {
+    let source = std::fs::read_to_string(path)
+        .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+    let json = if path.extension().is_some_and(|ext| ext == "toml") {
+        let toml = source
+            .parse::<toml::Table>()
+            .map_err(|e| format!("cannot parse {}: {}", path.display(), e))?;
+        serde_json::to_value(toml).map_err(|e| e.to_string())?
+    } else {
+        serde_json::from_str(&source)
+            .map_err(|e| format!("cannot parse {}: {}", path.display(), e))?
+    };
+    match value(json) {
+        Value::Map(values) => Ok(values),
+        _ => Err(format!("{} must hold a table of values", path.display())),
+    }
+}
+
+/// Template and stylesheet files under `dir`, except those starting with `_`.
+fn sources(dir: &Path, res: &mut Vec<PathBuf>) -> Result<(), String> {
+    let entries =
+        std::fs::read_dir(dir).map_err(|e| format!("cannot read {}: {}", dir.display(), e))?;
+    for entry in entries {
+        let path = entry.map_err(|e| e.to_string())?.path();
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        if name.starts_with('_') {
+            continue;
+        }
+        if path.is_dir() {
+            sources(&path, res)?;
+        } else if name.ends_with(TEMPLATE) {
+            res.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Renders every template and stylesheet of `templates` into the same place under `out`.
+pub fn render(templates: &Path, out: &Path, data: Option<&Path>) -> Result<(), String> {
+    let mut data = data.map(read_data).transpose()?.unwrap_or_default();
+    let pages = match data.remove("pages") {
+        Some(Value::Map(pages)) => pages,
+        _ => BTreeMap::new(),
+    };
+
+    let mut paths = vec![];
+    sources(templates, &mut paths)?;
+    paths.sort();
+    for path in paths {
+        let relative = path.strip_prefix(templates).unwrap_or(&path);
+        let relative = relative.to_string_lossy().replace('\\', "/");
+        let (name, extension) = match relative.strip_suffix(STYLESHEET) {
+            Some(name) => (name, "css"),
+            None => (relative.strip_suffix(TEMPLATE).unwrap_or(&relative), "html"),
+        };
+
+        let mut context = Context::new();
+        for (key, value) in data.iter() {
+            context.insert(key.as_str(), value.clone());
+        }
+        if let Some(Value::Map(page)) = pages.get(name) {
+            for (key, value) in page.iter() {
+                context.insert(key.as_str(), value.clone());
+            }
+        }
+
+        let error = |e: tent::runtime::Error| format!("{}: {}", path.display(), e);
+        let rendered = if extension == "css" {
+            Stylesheet::load(&path)
+                .and_then(|stylesheet| stylesheet.render(&context))
+                .map_err(error)?
+                .to_string()
+        } else {
+            Template::load(&path)
+                .and_then(|template| template.render(&context))
+                .map_err(error)?
+                .to_string()
+        };
+
+        let target = out.join(format!("{}.{}", name, extension));
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("cannot create {}: {}", parent.display(), e))?;
+        }
+        std::fs::write(&target, rendered)
+            .map_err(|e| format!("cannot write {}: {}", target.display(), e))?;
+    }
+    Ok(())
+}
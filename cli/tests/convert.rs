@@ -0,0 +1,27 @@
+use std::process::Command;
+
+#[test]
+fn test_convert() -> Result<(), failure::Error> {
+    let dir = std::env::temp_dir().join(format!("tent-convert-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    let page = dir.join("page.html");
+    std::fs::write(&page, "<div class=\"note\">\n  <p>Hi</p>\n</div>\n")?;
+    let styles = dir.join("styles.css");
+    std::fs::write(&styles, ".note p { font-size: 2em; }\n")?;
+
+    let convert = |path: &std::path::Path| -> Result<String, failure::Error> {
+        let output = Command::new(env!("CARGO_BIN_EXE_tent"))
+            .arg("convert")
+            .arg(path)
+            .output()?;
+        assert!(output.status.success());
+        Ok(String::from_utf8(output.stdout)?)
+    };
+    assert_eq!(convert(&page)?, "div.note\n    p \"Hi\"\n");
+    assert_eq!(
+        convert(&styles)?,
+        ".note\n    p\n        fontSize: \"2em\"\n"
+    );
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use std::path::PathBuf;
+use std::process::Command;
+
+fn work_dir(name: &str) -> Result<PathBuf, failure::Error> {
+    let dir = std::env::temp_dir().join(format!("tent-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+fn tent(args: &[&str]) -> Result<bool, failure::Error> {
+    Ok(Command::new(env!("CARGO_BIN_EXE_tent"))
+        .args(args)
+        .status()?
+        .success())
+}
+
+#[test]
+fn test_fmt() -> Result<(), failure::Error> {
+    let dir = work_dir("fmt")?;
+    let page = dir.join("page.tent");
+    std::fs::write(&page, "div\n  p   \"Hi\"  .note\n")?;
+    let styles = dir.join("styles.css.tent");
+    std::fs::write(&styles, ".note\n  color:\"red\"\n")?;
+    let dir_arg = dir.to_string_lossy().into_owned();
+
+    assert!(!tent(&["fmt", "--check", &dir_arg])?);
+    assert!(tent(&["fmt", &dir_arg])?);
+    assert!(tent(&["fmt", "--check", &dir_arg])?);
+    assert_eq!(std::fs::read_to_string(&page)?, "div\n    p.note \"Hi\"\n");
+    assert_eq!(
+        std::fs::read_to_string(&styles)?,
+        ".note\n    color: \"red\"\n"
+    );
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
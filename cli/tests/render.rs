@@ -0,0 +1,55 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn out_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("tent-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+fn read(dir: &Path, name: &str) -> Result<String, failure::Error> {
+    Ok(std::fs::read_to_string(dir.join(name))?)
+}
+
+#[test]
+fn test_render() -> Result<(), failure::Error> {
+    let site = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/site");
+    let out = out_dir("render");
+    let status = Command::new(env!("CARGO_BIN_EXE_tent"))
+        .arg("render")
+        .arg(&site)
+        .arg(&out)
+        .arg("--data")
+        .arg(site.join("data.toml"))
+        .status()?;
+    assert!(status.success());
+
+    assert_eq!(
+        read(&out, "index.html")?,
+        [
+            "<html><head><title>Tent</title></head>",
+            "<body><h1>Welcome</h1><p>Inkyu</p></body></html>",
+        ]
+        .join("")
+    );
+    assert_eq!(
+        read(&out, "blog/first.html")?,
+        [
+            "<html><head><title>First post</title></head>",
+            "<body><h1>First post</h1></body></html>",
+        ]
+        .join("")
+    );
+    assert_eq!(read(&out, "style.css")?, "body {color: #333;}");
+    assert!(!out.join("_layout.html").exists());
+    std::fs::remove_dir_all(&out)?;
+    Ok(())
+}
+
+#[test]
+fn test_usage() -> Result<(), failure::Error> {
+    let output = Command::new(env!("CARGO_BIN_EXE_tent")).output()?;
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)?.starts_with("Usage:"));
+    Ok(())
+}
@@ -1,5 +1,7 @@
 mod content;
-mod parser;
+pub mod module;
+mod scope;
 
-pub(self) use content::{Content, Item};
-pub use parser::Parser;
+pub use content::Expansion;
+pub use scope::{hash, ids, scope};
+pub use tent_syntax::css::Parser;
@@ -0,0 +1,81 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::Visibility;
+
+/// The field holding the stylesheet itself.
+const STYLESHEET: &str = "stylesheet";
+
+/// A field name for a class or an id, escaping rust keywords.
+pub fn field(name: &str) -> Result<Ident, String> {
+    let name = name.replace('-', "_");
+    if name == STYLESHEET {
+        return Err(format!(
+            "`{}` names the stylesheet of a css module and can not be a class or an id",
+            STYLESHEET
+        ));
+    }
+    syn::parse_str::<Ident>(&name)
+        .or_else(|_| syn::parse_str::<Ident>(&format!("r#{}", name)))
+        .map_err(|_| format!("`{}` can not be a field name", name))
+}
+
+/// A struct made for the stylesheet, with a `&str` field for each class and id.
+///
+/// A named struct is declared as an item, built with its `new` function. Otherwise the
+/// expansion is a value of a struct local to it.
+pub fn expand(
+    content: TokenStream,
+    classes: Vec<(String, String)>,
+    ids: Vec<String>,
+    name: Option<(&Visibility, &Ident)>,
+) -> Result<TokenStream, String> {
+    let mut fields = vec![];
+    let mut values = vec![];
+    for (name, value) in classes
+        .into_iter()
+        .chain(ids.into_iter().map(|id| (id.clone(), id)))
+    {
+        let field = field(&name)?;
+        if fields.contains(&field) {
+            return Err(format!("`{}` is both a class and an id", name));
+        }
+        fields.push(field);
+        values.push(value);
+    }
+    let stylesheet = Ident::new(STYLESHEET, Span::call_site());
+    let local = (
+        Visibility::Inherited,
+        Ident::new("CssModule", Span::call_site()),
+    );
+    let (vis, ty) = name.unwrap_or((&local.0, &local.1));
+    let definition = quote! {
+        #[allow(non_snake_case)]
+        #vis struct #ty {
+            #vis #stylesheet: tent::CssContent,
+            #(#vis #fields: &'static str,)*
+        }
+
+        impl #ty {
+            #vis fn new() -> Self {
+                Self {
+                    #stylesheet: #content,
+                    #(#fields: #values,)*
+                }
+            }
+        }
+
+        impl std::fmt::Display for #ty {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.#stylesheet.fmt(f)
+            }
+        }
+    };
+    Ok(match name {
+        Some(_) => definition,
+        None => quote! {{
+            #definition
+
+            CssModule::new()
+        }},
+    })
+}
@@ -0,0 +1,93 @@
+use tent_syntax::css::{selector, Content, Item};
+
+/// A short, stable hash for class name suffixes.
+pub fn hash(input: &str) -> String {
+    // FNV-1a, which unlike `DefaultHasher` doesn't change between compilers
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{:06x}", hash & 0xff_ffff)
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '-' || c == '_'
+}
+
+fn scope_selector(
+    selector: &str,
+    suffix: Option<&str>,
+    classes: &mut Vec<(String, String)>,
+) -> String {
+    let mut res = String::with_capacity(selector.len() + 7);
+    let mut rest = selector;
+    while let Some(index) = rest.find('.') {
+        res.push_str(&rest[..=index]);
+        rest = &rest[index + 1..];
+        let end = rest.find(|c| !is_name_char(c)).unwrap_or(rest.len());
+        let class = &rest[..end];
+        if !class.is_empty() {
+            let scoped = match suffix {
+                Some(suffix) => format!("{}-{}", class, suffix),
+                None => class.to_string(),
+            };
+            res.push_str(&scoped);
+            if !classes.iter().any(|(name, _)| name == class) {
+                classes.push((class.to_string(), scoped));
+            }
+        }
+        rest = &rest[end..];
+    }
+    res.push_str(rest);
+    res
+}
+
+/// Suffixes every class selector of a flattened stylesheet, returning the renamed classes.
+///
+/// Without a suffix the classes are listed as they are.
+pub fn scope(content: &mut Content, suffix: Option<&str>) -> Vec<(String, String)> {
+    let mut classes = vec![];
+    for item in content.items.iter_mut() {
+        scope_item(item, suffix, &mut classes);
+    }
+    classes
+}
+
+fn scope_item(item: &mut Item, suffix: Option<&str>, classes: &mut Vec<(String, String)>) {
+    match item {
+        // The rules held by an at-rule, like `@media (max-width: 600px)`
+        Item::Node { name, children } if name.starts_with('@') => {
+            for child in children.iter_mut() {
+                scope_item(child, suffix, classes);
+            }
+        }
+        Item::Node { name, .. } => *name = scope_selector(name, suffix, classes),
+        Item::Declaration(..) => (),
+    }
+}
+
+/// Ids of the selectors of a flattened stylesheet, like `idTest` for `#idTest`.
+pub fn ids(content: &Content) -> Vec<String> {
+    let mut ids: Vec<String> = vec![];
+    for item in content.items.iter() {
+        item_ids(item, &mut ids);
+    }
+    ids
+}
+
+fn item_ids(item: &Item, ids: &mut Vec<String>) {
+    match item {
+        Item::Node { name, children } if name.starts_with('@') => {
+            for child in children.iter() {
+                item_ids(child, ids);
+            }
+        }
+        Item::Node { name, .. } => {
+            for id in selector::names(name, '#') {
+                if !ids.iter().any(|known| known == id) {
+                    ids.push(id.to_string());
+                }
+            }
+        }
+        Item::Declaration(..) => (),
+    }
+}
@@ -0,0 +1,101 @@
+use std::collections::HashSet;
+
+use proc_macro2::Span;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Lit, LitStr, Meta, NestedMeta};
+
+use crate::html;
+
+/// Finds the `path` in `#[template(path = "...")]`.
+fn template_path(input: &DeriveInput) -> syn::Result<LitStr> {
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("template"))
+    {
+        if let Meta::List(list) = attr.parse_meta()? {
+            for nested in list.nested.iter() {
+                match nested {
+                    NestedMeta::Meta(Meta::NameValue(pair)) if pair.path.is_ident("path") => {
+                        if let Lit::Str(path) = &pair.lit {
+                            return Ok(path.clone());
+                        }
+                    }
+                    etc => return Err(syn::Error::new_spanned(etc, "expected `path = \"...\"`")),
+                }
+            }
+        }
+    }
+    Err(syn::Error::new(
+        Span::call_site(),
+        "missing `#[template(path = \"...\")]`",
+    ))
+}
+
+pub fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let literal = template_path(&input)?;
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => fields
+                .named
+                .iter()
+                .filter_map(|field| field.ident.clone())
+                .collect::<Vec<_>>(),
+            Fields::Unit => vec![],
+            Fields::Unnamed(_) => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "templates need named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "templates can only be derived for structs",
+            ))
+        }
+    };
+
+    let path = crate::resolve_path(&literal);
+    let parsed = html::Parsed::from_file(&path).map_err(|e| syn::Error::new(literal.span(), e))?;
+    let mut known = fields
+        .iter()
+        .map(|field| field.to_string())
+        .collect::<HashSet<_>>();
+    known.insert("self".to_string());
+    let unknown = html::unknown_identifiers(&parsed.content, &known);
+    if !unknown.is_empty() {
+        let message = format!(
+            "unknown identifier `{}` in template `{}`; it is not a field of `{}`",
+            unknown.join("`, `"),
+            literal.value(),
+            input.ident,
+        );
+        return Err(syn::Error::new(literal.span(), message));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let body = parsed.expand();
+    let size_hint = parsed.size_hint();
+    Ok(quote! {
+        impl #impl_generics tent::Template for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn render(&self) -> tent::HtmlContent {
+                let Self { #(#fields,)* } = self;
+                #body
+            }
+
+            fn size_hint(&self) -> usize {
+                #size_hint
+            }
+        }
+
+        impl #impl_generics std::fmt::Display for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                tent::Template::render_into(self, f)
+            }
+        }
+    })
+}
@@ -0,0 +1,268 @@
+use tent_syntax::util::unquote;
+
+use crate::html::Content;
+
+/// Environment variable enabling lints for every template, like a `lint` line.
+pub const LINT: &str = "TENT_LINT";
+
+#[derive(Clone, Copy, PartialEq)]
+enum Rule {
+    ImgAlt,
+    InputLabel,
+    AnchorHref,
+    HeadingOrder,
+    ButtonText,
+}
+
+const RULES: &[(&str, Rule)] = &[
+    ("imgAlt", Rule::ImgAlt),
+    ("inputLabel", Rule::InputLabel),
+    ("anchorHref", Rule::AnchorHref),
+    ("headingOrder", Rule::HeadingOrder),
+    ("buttonText", Rule::ButtonText),
+];
+
+/// Accessibility rules enabled for a template.
+#[derive(Default)]
+pub struct Config {
+    rules: Vec<Rule>,
+}
+
+impl Config {
+    /// Applies `TENT_LINT`, then the `lint` lines of the template: `a11y` enables every rule,
+    /// `a11y(imgAlt, headingOrder)` the given ones and `none` disables them again.
+    pub fn from_lints(lints: &[&str]) -> Result<Self, String> {
+        let mut config = Self::default();
+        if let Ok(lint) = std::env::var(LINT) {
+            config.apply(&lint)?;
+        }
+        for lint in lints.iter() {
+            config.apply(lint)?;
+        }
+        Ok(config)
+    }
+
+    fn apply(&mut self, lint: &str) -> Result<(), String> {
+        let lint = lint.trim();
+        if lint.is_empty() || lint == "none" {
+            self.rules.clear();
+        } else if lint == "a11y" {
+            self.rules = RULES.iter().map(|(_, rule)| *rule).collect();
+        } else if let Some(names) = lint.strip_prefix("a11y(").and_then(|s| s.strip_suffix(')')) {
+            self.rules.clear();
+            for name in names
+                .split(',')
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+            {
+                let rule = RULES
+                    .iter()
+                    .find(|(rule_name, _)| *rule_name == name)
+                    .ok_or_else(|| format!("unknown a11y rule `{}`", name))?;
+                self.rules.push(rule.1);
+            }
+        } else {
+            return Err(format!("unknown lint `{}`", lint));
+        }
+        Ok(())
+    }
+
+    fn enabled(&self, rule: Rule) -> bool {
+        self.rules.contains(&rule)
+    }
+}
+
+fn property<'a>(properties: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    properties
+        .iter()
+        .find(|(property, _)| property == name)
+        .map(|(_, value)| value.as_str())
+}
+
+/// `tag.class#id`, to point at an element in messages.
+fn describe(name: &str, class_names: &[String], properties: &[(String, String)]) -> String {
+    let mut res = name.to_string();
+    for class_name in class_names.iter() {
+        res.push('.');
+        res.push_str(class_name);
+    }
+    if let Some(id) = property(properties, "id").filter(|id| !id.starts_with('{')) {
+        res.push('#');
+        res.push_str(&unquote(id));
+    }
+    res
+}
+
+/// Whether the contents hold text a screen reader can announce.
+fn has_text(contents: &[Content]) -> bool {
+    contents.iter().any(|content| match content {
+        Content::Text(text) | Content::CData(text) => {
+            text.starts_with('{') || !unquote(text).trim().is_empty()
+        }
+        Content::Element {
+            name,
+            properties,
+            contents,
+            ..
+        } => (name == "img" && property(properties, "alt").is_some()) || has_text(contents),
+        // Rendered elsewhere, assume the best
+        Content::Component { .. } => true,
+        Content::Block { contents, .. } => has_text(contents),
+        Content::Extends { .. } => false,
+    })
+}
+
+fn label_targets(content: &Content, res: &mut Vec<String>) {
+    match content {
+        Content::Element {
+            name,
+            properties,
+            contents,
+            ..
+        } => {
+            if let Some(target) = property(properties, "for").filter(|_| name == "label") {
+                res.push(target.to_string());
+            }
+            for content in contents.iter() {
+                label_targets(content, res);
+            }
+        }
+        Content::Component { contents, .. } | Content::Block { contents, .. } => {
+            for content in contents.iter() {
+                label_targets(content, res);
+            }
+        }
+        Content::Text(_) | Content::CData(_) | Content::Extends { .. } => (),
+    }
+}
+
+struct Checker<'a> {
+    config: &'a Config,
+    label_targets: Vec<String>,
+    last_heading: Option<u32>,
+    errors: Vec<String>,
+}
+
+impl Checker<'_> {
+    fn report(&mut self, rule: Rule, message: String) {
+        if self.config.enabled(rule) {
+            self.errors.push(message);
+        }
+    }
+
+    fn element(
+        &mut self,
+        name: &str,
+        class_names: &[String],
+        properties: &[(String, String)],
+        contents: &[Content],
+        in_label: bool,
+    ) {
+        let has = |name: &str| property(properties, name).is_some();
+        let described = || describe(name, class_names, properties);
+        match name {
+            "img" if !has("alt") => self.report(
+                Rule::ImgAlt,
+                format!("`{}` needs an `alt` attribute", described()),
+            ),
+            "a" if !has("href") => self.report(
+                Rule::AnchorHref,
+                format!("`{}` needs an `href` attribute", described()),
+            ),
+            "input" => {
+                let kind = property(properties, "type").map(unquote);
+                let labelled = in_label
+                    || has("aria-label")
+                    || has("aria-labelledby")
+                    || has("title")
+                    || matches!(
+                        kind.as_deref(),
+                        Some("hidden" | "submit" | "reset" | "button" | "image")
+                    )
+                    || property(properties, "id").is_some_and(|id| {
+                        // A dynamic id might match a dynamic `for`
+                        id.starts_with('{')
+                            || self.label_targets.iter().any(|target| {
+                                target.starts_with('{') || unquote(target) == unquote(id)
+                            })
+                    });
+                if !labelled {
+                    self.report(
+                        Rule::InputLabel,
+                        format!("`{}` needs a `label` or an `aria-label`", described()),
+                    );
+                }
+            }
+            "button" if !has_text(contents) && !has("aria-label") && !has("title") => self.report(
+                Rule::ButtonText,
+                format!("`{}` needs text or an `aria-label`", described()),
+            ),
+            _ => (),
+        }
+
+        let heading = name
+            .strip_prefix('h')
+            .and_then(|level| level.parse::<u32>().ok())
+            .filter(|level| (1..=6).contains(level));
+        if let Some(level) = heading {
+            if let Some(last) = self.last_heading.filter(|last| level > last + 1) {
+                self.report(
+                    Rule::HeadingOrder,
+                    format!(
+                        "`{}` follows `h{}`, skipping a heading level",
+                        described(),
+                        last
+                    ),
+                );
+            }
+            self.last_heading = Some(level);
+        }
+
+        for content in contents.iter() {
+            self.content(content, in_label || name == "label");
+        }
+    }
+
+    fn content(&mut self, content: &Content, in_label: bool) {
+        match content {
+            Content::Element {
+                name,
+                class_names,
+                properties,
+                contents,
+            } => self.element(name, class_names, properties, contents, in_label),
+            Content::Component { contents, .. } | Content::Block { contents, .. } => {
+                for content in contents.iter() {
+                    self.content(content, in_label);
+                }
+            }
+            Content::Text(_) | Content::CData(_) | Content::Extends { .. } => (),
+        }
+    }
+}
+
+/// Runs the enabled accessibility rules over a template.
+pub fn check(content: &Content, config: &Config) -> Result<(), String> {
+    if config.rules.is_empty() {
+        return Ok(());
+    }
+    let mut targets = vec![];
+    label_targets(content, &mut targets);
+    let mut checker = Checker {
+        config,
+        label_targets: targets,
+        last_heading: None,
+        errors: vec![],
+    };
+    checker.content(content, false);
+    if checker.errors.is_empty() {
+        Ok(())
+    } else {
+        Err(checker
+            .errors
+            .iter()
+            .map(|error| format!("a11y: {}", error))
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+}
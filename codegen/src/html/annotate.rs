@@ -0,0 +1,12 @@
+use std::path::Path;
+
+pub use tent_syntax::html::Annotation;
+
+/// A template path as shown in annotations, relative to the manifest directory.
+pub fn display_path(path: &Path) -> String {
+    let root = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    path.strip_prefix(&root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .into_owned()
+}
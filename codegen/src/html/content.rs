@@ -1,25 +1,56 @@
 use std::fmt;
 
-#[derive(Debug)]
-pub enum Content {
-    Element {
-        name: String,
-        class_names: Vec<String>,
-        properties: Vec<(String, String)>,
-        contents: Vec<Content>,
-    },
-    Text(String),
+use tent_syntax::html::{Content, SOURCE_ATTRIBUTE};
+
+use crate::css::module::field;
+
+/// Unwraps a `{}` group holding a single expression, so its value is borrowed rather than moved
+/// out of a block.
+pub(crate) fn value(source: &str) -> String {
+    if let Some(inner) = source.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+        if syn::parse_str::<syn::Expr>(inner).is_ok() {
+            return format!("({})", inner);
+        }
+    }
+    source.to_string()
 }
 
-impl fmt::Display for Content {
+/// Rust code building the `tent::HtmlContent` described by a parsed template.
+pub struct Expansion<'a> {
+    pub content: &'a Content,
+    /// The css module holding the class names, as given by `scoped styles`.
+    pub scope: Option<&'a str>,
+    /// Whether source locations become comments rather than attributes.
+    pub comments: bool,
+}
+
+impl<'a> Expansion<'a> {
+    fn child(&self, content: &'a Content) -> Self {
+        Self {
+            content,
+            scope: self.scope,
+            comments: self.comments,
+        }
+    }
+}
+
+impl fmt::Display for Expansion<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
+        match self.content {
             Content::Element {
                 name,
                 class_names,
                 properties,
                 contents,
             } => {
+                // Source locations only show in debug builds
+                let source = properties
+                    .iter()
+                    .find(|(name, _)| name == SOURCE_ATTRIBUTE)
+                    .map(|(_, value)| value);
+                if let (Some(_), true) = (source, self.comments) {
+                    write!(f, "{{ let element = ")?;
+                }
                 write!(
                     f,
                     "tent::HtmlContent::Element {{ name: \"{}\".to_string(), ",
@@ -27,21 +58,83 @@ impl fmt::Display for Content {
                 )?;
                 write!(f, "class_names: vec![")?;
                 for class_name in class_names.iter() {
-                    write!(f, "\"{}\".to_string(),", class_name)?;
+                    if class_name.starts_with('{') {
+                        write!(f, "{}.to_string(),", value(class_name))?;
+                        continue;
+                    }
+                    // Fields of the css module, so unknown classes don't compile
+                    match self.scope.map(|scope| (scope, field(class_name))) {
+                        Some((scope, Ok(field))) => {
+                            write!(f, "({}).{}.to_string(),", scope, field)?
+                        }
+                        Some((_, Err(e))) => write!(f, "compile_error!({:?}),", e)?,
+                        None => write!(f, "\"{}\".to_string(),", class_name)?,
+                    }
                 }
                 write!(f, "], ")?;
-                write!(f, "properties: vec![")?;
+                let source_attribute = source.filter(|_| !self.comments);
+                match source_attribute {
+                    Some(_) => write!(f, "properties: {{ let mut properties = vec![")?,
+                    None => write!(f, "properties: vec![")?,
+                }
                 for (name, value) in properties.iter() {
-                    write!(f, "(\"{}\".to_string(),{}.to_string()),", name, value)?;
+                    if name == SOURCE_ATTRIBUTE {
+                        continue;
+                    }
+                    write!(
+                        f,
+                        "({:?}.to_string(),{}.to_string()),",
+                        name,
+                        self::value(value)
+                    )?;
+                }
+                match source_attribute {
+                    Some(source) => write!(
+                        f,
+                        "]; if cfg!(debug_assertions) {{ properties.push((\"{}\".to_string(), {}.to_string())); }} properties }}, ",
+                        SOURCE_ATTRIBUTE, source
+                    )?,
+                    None => write!(f, "], ")?,
                 }
-                write!(f, "], ")?;
                 write!(f, "contents: vec![")?;
                 for content in contents.iter() {
-                    write!(f, "{},", content)?;
+                    write!(f, "{},", self.child(content))?;
+                }
+                write!(f, "] }}")?;
+                if let (Some(source), true) = (source, self.comments) {
+                    write!(
+                        f,
+                        "; if cfg!(debug_assertions) {{ tent::HtmlContent::Fragment(vec![tent::HtmlContent::Text(concat!(\"<!-- tent: \", {}, \" -->\").to_string()), element]) }} else {{ element }} }}",
+                        source
+                    )?;
+                }
+                Ok(())
+            }
+            Content::Text(text) => {
+                write!(f, "tent::HtmlContent::Text({}.to_string())", value(text))
+            }
+            Content::Component {
+                name,
+                properties,
+                contents,
+            } => {
+                write!(f, "tent::Component::render({} {{ ", name)?;
+                for (name, value) in properties.iter() {
+                    write!(f, "{}: {}.into(), ", name, self::value(value))?;
+                }
+                if !contents.is_empty() {
+                    write!(f, "children: tent::HtmlContent::Fragment(vec![")?;
+                    for content in contents.iter() {
+                        write!(f, "{},", self.child(content))?;
+                    }
+                    write!(f, "]), ")?;
                 }
-                write!(f, "] }}")
+                write!(f, "}})")
+            }
+            Content::Block { .. } | Content::Extends { .. } => {
+                unreachable!("Layouts are resolved before expansion")
             }
-            Content::Text(text) => write!(f, "tent::HtmlContent::Text({}.to_string())", text),
+            Content::CData(_) => unreachable!("CDATA sections are rejected by validation"),
         }
     }
 }
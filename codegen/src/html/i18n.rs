@@ -0,0 +1,264 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::visit_mut::{self, VisitMut};
+use syn::{Expr, LitStr, Macro, Token};
+
+use crate::html::Content;
+use crate::Binding;
+
+/// Environment variable naming a directory of message catalogs, relative to the manifest
+/// directory. Every key a template looks up must be in each of them.
+pub const CATALOG: &str = "TENT_CATALOG";
+
+/// Arguments of `t!("key", name = value, ...)`.
+struct Lookup {
+    key: LitStr,
+    arguments: Punctuated<Binding, Token![,]>,
+}
+
+impl Parse for Lookup {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key = input.parse()?;
+        let arguments = if input.parse::<Option<Token![,]>>()?.is_some() {
+            Punctuated::parse_terminated(input)?
+        } else {
+            Punctuated::new()
+        };
+        Ok(Self { key, arguments })
+    }
+}
+
+/// Replaces `t!` lookups with calls of the translator named by the `translate` line.
+struct Translate<'a> {
+    translator: Option<&'a Expr>,
+    keys: &'a mut Vec<String>,
+    errors: Vec<String>,
+    changed: bool,
+}
+
+impl Translate<'_> {
+    fn lookup(&mut self, mac: &Macro) -> Result<Expr, String> {
+        let translator = self
+            .translator
+            .ok_or("`t!` needs a `translate` line naming the translator")?;
+        let lookup = mac
+            .parse_body::<Lookup>()
+            .map_err(|e| format!("invalid `t!`: {}", e))?;
+        let key = &lookup.key;
+        self.keys.push(key.value());
+        let names = lookup
+            .arguments
+            .iter()
+            .map(|binding| binding.name.to_string());
+        let values = lookup.arguments.iter().map(|binding| &binding.value);
+        Ok(syn::parse_quote! {
+            tent::Translator::translate(&(#translator), #key, &[#((#names, (#values).to_string())),*])
+        })
+    }
+}
+
+impl VisitMut for Translate<'_> {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        if let Expr::Macro(mac) = expr {
+            if mac.mac.path.is_ident("t") {
+                match self.lookup(&mac.mac) {
+                    Ok(lookup) => {
+                        *expr = lookup;
+                        self.changed = true;
+                    }
+                    Err(e) => self.errors.push(e),
+                }
+                return;
+            }
+        }
+        visit_mut::visit_expr_mut(self, expr);
+    }
+}
+
+impl Translate<'_> {
+    fn value(&mut self, value: &mut String) {
+        if !value.starts_with('{') {
+            return;
+        }
+        let mut block = match syn::parse_str::<syn::Block>(value) {
+            Ok(block) => block,
+            Err(_) => return,
+        };
+        self.changed = false;
+        self.visit_block_mut(&mut block);
+        if self.changed {
+            *value = quote!(#block).to_string();
+        }
+    }
+
+    fn content(&mut self, content: &mut Content) {
+        match content {
+            Content::Element {
+                properties,
+                contents,
+                ..
+            }
+            | Content::Component {
+                properties,
+                contents,
+                ..
+            } => {
+                for (_, value) in properties.iter_mut() {
+                    self.value(value);
+                }
+                for content in contents.iter_mut() {
+                    self.content(content);
+                }
+            }
+            Content::Text(text) => self.value(text),
+            Content::Block { contents, .. } => {
+                for content in contents.iter_mut() {
+                    self.content(content);
+                }
+            }
+            Content::Extends { blocks, .. } => {
+                for block in blocks.iter_mut() {
+                    self.content(block);
+                }
+            }
+            Content::CData(_) => (),
+        }
+    }
+}
+
+/// Rewrites the `t!` lookups of a template, giving the keys they look up.
+pub fn translate(content: &mut Content, translator: Option<&str>) -> Result<Vec<String>, String> {
+    let translator = translator
+        .map(|translator| {
+            syn::parse_str::<Expr>(translator)
+                .map_err(|_| format!("invalid translator `{}`", translator))
+        })
+        .transpose()?;
+    let mut keys = vec![];
+    let mut translate = Translate {
+        translator: translator.as_ref(),
+        keys: &mut keys,
+        errors: vec![],
+        changed: false,
+    };
+    translate.content(content);
+    if translate.errors.is_empty() {
+        Ok(keys)
+    } else {
+        Err(translate.errors.join("\n"))
+    }
+}
+
+/// Flattens a JSON catalog into dotted keys, like `nav.home`.
+fn json_keys(value: &serde_json::Value, prefix: &str, res: &mut HashSet<String>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter() {
+                let key = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                json_keys(value, &key, res);
+            }
+        }
+        _ => {
+            res.insert(prefix.to_string());
+        }
+    }
+}
+
+/// Whether `name` is a Fluent identifier, which can't have dots, unlike the keys of lookups.
+fn is_fluent_identifier(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Message ids of a Fluent catalog, with attributes as `message.attribute`.
+fn fluent_keys(source: &str) -> Result<HashSet<String>, String> {
+    let mut res = HashSet::new();
+    let mut message = None;
+    for (number, line) in source.lines().enumerate() {
+        let trimmed = line.trim_start();
+        let name = |name: &str| {
+            let name = name.trim();
+            if is_fluent_identifier(name) {
+                Ok(name.to_string())
+            } else {
+                Err(format!(
+                    "line {}: invalid identifier `{}`",
+                    number + 1,
+                    name
+                ))
+            }
+        };
+        if line.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            if let Some((id, _)) = line.split_once('=') {
+                let id = name(id)?;
+                res.insert(id.clone());
+                message = Some(id);
+            }
+        } else if let (Some(message), Some(attribute)) = (&message, trimmed.strip_prefix('.')) {
+            if let Some((attribute, _)) = attribute.split_once('=') {
+                res.insert(format!("{}.{}", message, name(attribute)?));
+            }
+        } else if line.starts_with(['#', '-']) {
+            message = None;
+        }
+    }
+    Ok(res)
+}
+
+/// Checks the keys against each catalog of `TENT_CATALOG`, giving the catalog files read.
+pub fn check(keys: &[String]) -> Result<Vec<PathBuf>, String> {
+    let dir = match std::env::var(CATALOG) {
+        Ok(dir) if !keys.is_empty() => dir,
+        _ => return Ok(vec![]),
+    };
+    let root = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let dir = Path::new(&root).join(dir);
+    let mut catalogs = std::fs::read_dir(&dir)
+        .map_err(|e| format!("cannot read {}: {}", dir.display(), e))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            matches!(
+                path.extension().and_then(|ext| ext.to_str()),
+                Some("json" | "ftl")
+            )
+        })
+        .collect::<Vec<_>>();
+    catalogs.sort();
+
+    let mut errors = vec![];
+    for path in catalogs.iter() {
+        let source = std::fs::read_to_string(path)
+            .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+        let defined = if path.extension().is_some_and(|ext| ext == "json") {
+            let value = serde_json::from_str(&source)
+                .map_err(|e| format!("cannot parse {}: {}", path.display(), e))?;
+            let mut res = HashSet::new();
+            json_keys(&value, "", &mut res);
+            res
+        } else {
+            fluent_keys(&source).map_err(|e| format!("cannot parse {}: {}", path.display(), e))?
+        };
+        let locale = path.file_stem().unwrap_or_default().to_string_lossy();
+        for key in keys.iter().filter(|key| !defined.contains(*key)) {
+            errors.push(format!(
+                "missing translation `{}` for locale `{}`",
+                key, locale
+            ));
+        }
+    }
+    if errors.is_empty() {
+        Ok(catalogs)
+    } else {
+        Err(errors.join("\n"))
+    }
+}
@@ -1,5 +1,189 @@
+mod a11y;
+mod annotate;
 mod content;
-mod parser;
+mod i18n;
+mod scope;
+mod spec;
+mod validate;
 
-pub(self) use content::Content;
-pub use parser::Parser;
+use std::path::Path;
+
+use quote::quote;
+
+pub(crate) use content::value;
+pub use content::Expansion;
+pub use scope::unknown_identifiers;
+pub use tent_syntax::html::{Content, Parser};
+
+use annotate::{display_path, Annotation};
+
+/// Environment variables read while expanding a template.
+const ENVIRONMENT: &[&str] = &[
+    validate::ALLOWED_TAGS,
+    a11y::LINT,
+    i18n::CATALOG,
+    tent_syntax::html::ANNOTATE,
+];
+
+/// Items reading the environment variables a template depends on, so the compiler rebuilds it
+/// when they change. Errors need them as well, to go away once the variables are fixed.
+pub fn track_environment() -> proc_macro2::TokenStream {
+    let names = ENVIRONMENT.iter();
+    quote! {
+        #(const _: Option<&str> = option_env!(#names);)*
+    }
+}
+
+/// A template with the layouts it extends resolved.
+pub struct Parsed {
+    pub content: Content,
+    /// Layout files read while resolving, rebuilt into pages with `include_str!`.
+    pub dependencies: Vec<String>,
+    /// The stylesheet named by `scoped`, whose hashed class names the template uses.
+    pub scope: Option<String>,
+    pub annotation: Option<Annotation>,
+}
+
+impl Parsed {
+    /// Parses an html template, resolving the layouts it extends relative to `base`. `file` is
+    /// the template file, if any, and `origin` the file and line number the template starts at.
+    pub fn from_source(
+        source: &str,
+        base: &Path,
+        file: Option<&Path>,
+        origin: (&str, usize),
+    ) -> Result<Self, String> {
+        let mut parser =
+            Parser::from_str(source).map_err(|e| format!("cannot parse template: {}", e))?;
+        let annotation = Annotation::from_directives(&parser.directives("annotate"))?;
+        if annotation.is_some() {
+            parser.annotate(origin.0, origin.1);
+        }
+        let lints = a11y::Config::from_lints(&parser.directives("lint"))?;
+        let scope = match parser.directives("scoped").as_slice() {
+            [] => None,
+            [scope] => Some(scope.to_string()),
+            _ => return Err("a template can be `scoped` by a single stylesheet".to_string()),
+        };
+        let translator = match parser.directives("translate").as_slice() {
+            [] => None,
+            [translator] => Some(translator.to_string()),
+            _ => return Err("a template can have a single `translate` line".to_string()),
+        };
+        let parsed = parser
+            .build()
+            .map_err(|e| format!("cannot parse template: {}", e))?;
+        let mut dependencies = vec![];
+        let mut content = parsed.resolve(base, file, &mut |path: &Path| {
+            let source = std::fs::read_to_string(path)
+                .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+            dependencies.push(path.to_string_lossy().into_owned());
+            let mut parser = Parser::from_str(&source)
+                .map_err(|e| format!("cannot parse {}: {}", path.display(), e))?;
+            if annotation.is_some() {
+                parser.annotate(&display_path(path), 1);
+            }
+            parser
+                .build()
+                .map_err(|e| format!("cannot parse {}: {}", path.display(), e))
+        })?;
+        let keys = i18n::translate(&mut content, translator.as_deref())?;
+        for catalog in i18n::check(&keys)? {
+            dependencies.push(catalog.to_string_lossy().into_owned());
+        }
+        validate::validate(&content)?;
+        a11y::check(&content, &lints)?;
+        Ok(Self {
+            content,
+            dependencies,
+            scope,
+            annotation,
+        })
+    }
+
+    /// Reads and parses an html template file, resolving layouts relative to its directory.
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        let source = std::fs::read_to_string(path)
+            .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+        let mut parsed = Self::from_source(
+            &source,
+            path.parent().unwrap_or_else(|| Path::new("")),
+            Some(path),
+            (&display_path(path), 1),
+        )?;
+        parsed
+            .dependencies
+            .insert(0, path.to_string_lossy().into_owned());
+        Ok(parsed)
+    }
+
+    /// Length of the rendered output without the `{}` groups.
+    pub fn size_hint(&self) -> usize {
+        static_size(&self.content)
+    }
+
+    pub fn expand(&self) -> proc_macro2::TokenStream {
+        let dependencies = &self.dependencies;
+        let body: proc_macro2::TokenStream = format!(
+            "{}",
+            Expansion {
+                content: &self.content,
+                scope: self.scope.as_deref(),
+                comments: self.annotation == Some(Annotation::Comment),
+            }
+        )
+        .parse()
+        .unwrap();
+        let environment = track_environment();
+        quote! {{
+            #(const _: &str = include_str!(#dependencies);)*
+            #environment
+            #body
+        }}
+    }
+}
+
+fn static_size(content: &Content) -> usize {
+    let value_size = |value: &str| {
+        if value.starts_with('{') {
+            0
+        } else {
+            tent_syntax::util::unquote(value).len()
+        }
+    };
+    match content {
+        Content::Element {
+            name,
+            class_names,
+            properties,
+            contents,
+        } => {
+            let class_size = match class_names.len() {
+                0 => 0,
+                len => {
+                    " class=\"\"".len()
+                        + class_names
+                            .iter()
+                            .map(|name| value_size(name))
+                            .sum::<usize>()
+                        + len
+                        - 1
+                }
+            };
+            let properties_size = properties
+                .iter()
+                .map(|(name, value)| name.len() + " =\"\"".len() + value_size(value))
+                .sum::<usize>();
+            "<></>".len()
+                + name.len() * 2
+                + class_size
+                + properties_size
+                + contents.iter().map(static_size).sum::<usize>()
+        }
+        Content::Text(text) | Content::CData(text) => value_size(text),
+        Content::Component { contents, .. } | Content::Block { contents, .. } => {
+            contents.iter().map(static_size).sum()
+        }
+        Content::Extends { blocks, .. } => blocks.iter().map(static_size).sum(),
+    }
+}
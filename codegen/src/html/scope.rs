@@ -0,0 +1,116 @@
+use std::collections::HashSet;
+
+use syn::visit::{self, Visit};
+use syn::{Expr, ExprCall, ExprMethodCall, ExprPath, PatIdent};
+
+use crate::html::Content;
+
+/// Collects local-variable-like names an expression refers to without binding them itself.
+struct References<'a> {
+    known: &'a HashSet<String>,
+    locals: HashSet<String>,
+    unknown: Vec<String>,
+}
+
+impl<'ast> References<'_> {
+    /// Visits call arguments. Functions and constants in scope are passed by name, like `label`
+    /// in `.map(label)`, so a bare name there is left for rustc to resolve.
+    fn visit_args(&mut self, args: impl Iterator<Item = &'ast Expr>) {
+        for arg in args {
+            if !matches!(arg, Expr::Path(_)) {
+                self.visit_expr(arg);
+            }
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for References<'_> {
+    fn visit_expr_path(&mut self, expr: &'ast ExprPath) {
+        let path = &expr.path;
+        if expr.qself.is_some() || path.leading_colon.is_some() || path.segments.len() != 1 {
+            return;
+        }
+        let name = path.segments[0].ident.to_string();
+        // Types, constants and variants start with an uppercase letter.
+        if !name.starts_with(|c: char| c.is_lowercase() || c == '_')
+            || self.known.contains(&name)
+            || self.locals.contains(&name)
+            || self.unknown.contains(&name)
+        {
+            return;
+        }
+        self.unknown.push(name);
+    }
+
+    fn visit_expr_call(&mut self, call: &'ast ExprCall) {
+        // Free functions are not variables
+        if !matches!(*call.func, Expr::Path(_)) {
+            self.visit_expr(&call.func);
+        }
+        self.visit_args(call.args.iter());
+    }
+
+    fn visit_expr_method_call(&mut self, call: &'ast ExprMethodCall) {
+        self.visit_expr(&call.receiver);
+        if let Some(turbofish) = &call.turbofish {
+            self.visit_method_turbofish(turbofish);
+        }
+        self.visit_args(call.args.iter());
+    }
+
+    fn visit_pat_ident(&mut self, pat: &'ast PatIdent) {
+        // Bindings of closures, `match` arms and `let` are in scope for the rest of the group.
+        self.locals.insert(pat.ident.to_string());
+        visit::visit_pat_ident(self, pat);
+    }
+}
+
+fn expressions<'a>(content: &'a Content, res: &mut Vec<&'a str>) {
+    match content {
+        Content::Element {
+            properties,
+            contents,
+            ..
+        }
+        | Content::Component {
+            properties,
+            contents,
+            ..
+        } => {
+            res.extend(properties.iter().map(|(_, value)| value.as_str()));
+            for content in contents.iter() {
+                expressions(content, res);
+            }
+        }
+        Content::Text(text) | Content::CData(text) => res.push(text),
+        Content::Block { contents, .. } => {
+            for content in contents.iter() {
+                expressions(content, res);
+            }
+        }
+        Content::Extends { blocks, .. } => {
+            for block in blocks.iter() {
+                expressions(block, res);
+            }
+        }
+    }
+}
+
+/// Lists the variables used by `{}` groups of the template which are not in `known`.
+pub fn unknown_identifiers(content: &Content, known: &HashSet<String>) -> Vec<String> {
+    let mut sources = vec![];
+    expressions(content, &mut sources);
+
+    let mut references = References {
+        known,
+        locals: HashSet::new(),
+        unknown: vec![],
+    };
+    for source in sources.into_iter().filter(|source| source.starts_with('{')) {
+        if let Ok(expr) = syn::parse_str::<Expr>(source) {
+            references.locals.clear();
+            references.visit_expr(&expr);
+        }
+    }
+    references.unknown
+}
@@ -0,0 +1,147 @@
+use tent_syntax::svg::ELEMENTS as SVG_ELEMENTS;
+
+use crate::html::spec::{element_attributes, GLOBAL_ATTRIBUTES, HTML_ELEMENTS};
+use crate::html::Content;
+
+/// Environment variable listing custom tags, separated by commas.
+pub const ALLOWED_TAGS: &str = "TENT_ALLOWED_TAGS";
+
+/// Edit distance counting a swap of adjacent characters as a single edit.
+fn distance(a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+    let mut d = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    d[a.len()][b.len()]
+}
+
+/// The closest candidate, if it is close enough to be a typo of `name`.
+fn suggest<'a>(name: &str, candidates: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    let threshold = if name.len() > 4 { 2 } else { 1 };
+    candidates
+        .map(|candidate| (distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= threshold)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+struct Validator {
+    allowed_tags: Vec<String>,
+    errors: Vec<String>,
+}
+
+impl Validator {
+    fn element(&mut self, name: &str, in_svg: bool) -> bool {
+        let elements = if in_svg { SVG_ELEMENTS } else { HTML_ELEMENTS };
+        if elements.contains(&name)
+            || name.contains('-')
+            || self.allowed_tags.iter().any(|tag| tag == name)
+        {
+            return true;
+        }
+        self.errors
+            .push(match suggest(name, elements.iter().copied()) {
+                Some(candidate) => {
+                    format!("unknown element `{}`, did you mean `{}`?", name, candidate)
+                }
+                None => format!(
+                    "unknown element `{}`; custom elements are named with a dash, like `my-{}`, \
+                     or allowed through {}",
+                    name, name, ALLOWED_TAGS
+                ),
+            });
+        false
+    }
+
+    /// Only reports likely typos, as frameworks and microformats add attributes of their own.
+    fn attribute(&mut self, element: &str, name: &str) {
+        let specific = element_attributes(element);
+        let known = || GLOBAL_ATTRIBUTES.iter().chain(specific.iter()).copied();
+        if known().any(|attribute| attribute == name)
+            || name.starts_with("on")
+            || name.contains('-')
+            || name.contains(':')
+        {
+            return;
+        }
+        if let Some(candidate) = suggest(name, known()) {
+            self.errors.push(format!(
+                "unknown attribute `{}` on `{}`, did you mean `{}`?",
+                name, element, candidate
+            ));
+        }
+    }
+
+    fn content(&mut self, content: &Content, in_svg: bool) {
+        match content {
+            Content::Element {
+                name,
+                properties,
+                contents,
+                ..
+            } => {
+                let in_svg = in_svg || name == "svg";
+                if self.element(name, in_svg) && !in_svg {
+                    for (attribute, _) in properties.iter() {
+                        self.attribute(name, attribute);
+                    }
+                }
+                // The contents of `foreignObject` are html again
+                let in_svg = in_svg && name != "foreignObject";
+                for content in contents.iter() {
+                    self.content(content, in_svg);
+                }
+            }
+            Content::Component { contents, .. } | Content::Block { contents, .. } => {
+                for content in contents.iter() {
+                    self.content(content, in_svg);
+                }
+            }
+            Content::Extends { blocks, .. } => {
+                for block in blocks.iter() {
+                    self.content(block, in_svg);
+                }
+            }
+            Content::Text(_) => (),
+            Content::CData(_) => self
+                .errors
+                .push("CDATA sections are only supported by `xml!`".to_string()),
+        }
+    }
+}
+
+/// Checks element and attribute names against HTML5 and SVG.
+pub fn validate(content: &Content) -> Result<(), String> {
+    let allowed_tags = std::env::var(ALLOWED_TAGS)
+        .unwrap_or_default()
+        .split(',')
+        .map(|tag| tag.trim().to_string())
+        .filter(|tag| !tag.is_empty())
+        .collect();
+    let mut validator = Validator {
+        allowed_tags,
+        errors: vec![],
+    };
+    validator.content(content, false);
+    if validator.errors.is_empty() {
+        Ok(())
+    } else {
+        Err(validator.errors.join("\n"))
+    }
+}
@@ -1,23 +1,289 @@
 mod css;
+mod derive;
 mod html;
-mod util;
+mod template;
+mod value;
+mod xml;
+
+use std::path::{Path, PathBuf};
 
 use proc_macro::TokenStream;
-use syn::{parse_macro_input, LitStr};
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{parse_macro_input, DeriveInput, Expr, Ident, ItemFn, LitStr, Token};
 
 #[proc_macro]
 pub fn html(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as LitStr);
-    let parser = html::Parser::from_str(input.value().as_str()).unwrap();
-    let parsed = parser.build().unwrap();
-    format!("{}", parsed).parse().unwrap()
+    let root = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let span = input.span().unwrap();
+    let origin = (span.file(), span.line());
+    match html::Parsed::from_source(
+        input.value().as_str(),
+        Path::new(&root),
+        None,
+        (&origin.0, origin.1),
+    ) {
+        Ok(parsed) => parsed.expand().into(),
+        Err(message) => {
+            let environment = html::track_environment();
+            let error = syn::Error::new(input.span(), message).to_compile_error();
+            quote! {{ #environment #error }}.into()
+        }
+    }
 }
 
+/// Builds a `tent::XmlContent` from a template.
+///
+/// Names are kept exactly as written. Lines like `?xml version="1.0"` are processing
+/// instructions, and `!cdata "text"` is a CDATA section.
 #[proc_macro]
-pub fn css(input: TokenStream) -> TokenStream {
+pub fn xml(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as LitStr);
-    let parser = css::Parser::from_str(input.value().as_str()).unwrap();
-    let parsed = parser.build();
-    let flatten = parsed.flatten();
-    format!("{}", flatten).parse().unwrap()
+    match xml::expand(input.value().as_str()) {
+        Ok(expanded) => expanded.into(),
+        Err(message) => syn::Error::new(input.span(), message)
+            .to_compile_error()
+            .into(),
+    }
+}
+
+struct CssInput {
+    scoped: bool,
+    module: bool,
+    /// The type of `css!(module Name, ...)`, declared as an item.
+    name: Option<(syn::Visibility, Ident)>,
+    source: LitStr,
+}
+
+impl Parse for CssInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut res = Self {
+            scoped: false,
+            module: false,
+            name: None,
+            source: LitStr::new("", proc_macro2::Span::call_site()),
+        };
+        while input.peek(Ident) {
+            let mode: Ident = input.parse()?;
+            match mode.to_string().as_str() {
+                "scoped" => res.scoped = true,
+                "module" => {
+                    res.module = true;
+                    if !input.peek(Token![,]) {
+                        res.name = Some((input.parse()?, input.parse()?));
+                    }
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        mode.span(),
+                        "expected `scoped` or `module`",
+                    ))
+                }
+            }
+            input.parse::<Token![,]>()?;
+        }
+        res.source = input.parse()?;
+        Ok(res)
+    }
+}
+
+/// Builds a `tent::CssContent` from a stylesheet.
+///
+/// `css!(scoped, ...)` suffixes class names with a hash of the call site and gives a
+/// `tent::ScopedCssContent`. `css!(module, ...)` gives a struct with a field for each class
+/// and id instead, holding the stylesheet in its `stylesheet` field. `css!(module Styles, ...)`
+/// declares that struct as `Styles`, made with `Styles::new()`, and goes where items do.
+///
+/// A template with `scoped styles` takes its static class names from the fields of the css
+/// module `styles`, so a class the stylesheet doesn't define fails to compile. Other classes
+/// are given as expressions, like `.{"global"}`.
+#[proc_macro]
+pub fn css(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as CssInput);
+    let source = input.source.value();
+    let parsed = match css::Parser::from_str(&source).and_then(css::Parser::build) {
+        Ok(parsed) => parsed,
+        Err(message) => {
+            return syn::Error::new(
+                input.source.span(),
+                format!("cannot parse stylesheet: {}", message),
+            )
+            .to_compile_error()
+            .into()
+        }
+    };
+    let mut flatten = parsed.flatten();
+    if !input.scoped && !input.module {
+        return format!("{}", css::Expansion(&flatten)).parse().unwrap();
+    }
+
+    let suffix = input.scoped.then(|| {
+        let span = proc_macro::Span::call_site();
+        let site = format!(
+            "{}:{}:{}:{}",
+            span.file(),
+            span.line(),
+            span.column(),
+            source
+        );
+        css::hash(&site)
+    });
+    let classes = css::scope(&mut flatten, suffix.as_deref());
+    let content: proc_macro2::TokenStream =
+        format!("{}", css::Expansion(&flatten)).parse().unwrap();
+    if input.module {
+        let ids = css::ids(&flatten);
+        let name = input.name.as_ref().map(|(vis, name)| (vis, name));
+        return css::module::expand(content, classes, ids, name)
+            .unwrap_or_else(|e| syn::Error::new(input.source.span(), e).to_compile_error())
+            .into();
+    }
+
+    let (names, scoped): (Vec<_>, Vec<_>) = classes.into_iter().unzip();
+    quote! {
+        tent::ScopedCssContent {
+            content: #content,
+            scope: tent::CssScope {
+                classes: vec![#((#names.to_string(), #scoped.to_string()),)*],
+            },
+        }
+    }
+    .into()
+}
+
+struct Binding {
+    name: Ident,
+    value: Expr,
+}
+
+impl Parse for Binding {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let value = input.parse()?;
+        Ok(Self { name, value })
+    }
+}
+
+struct FileInput {
+    path: LitStr,
+    bindings: Punctuated<Binding, Token![,]>,
+}
+
+impl Parse for FileInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path = input.parse()?;
+        let bindings = if input.parse::<Option<Token![,]>>()?.is_some() {
+            Punctuated::parse_terminated(input)?
+        } else {
+            Punctuated::new()
+        };
+        Ok(Self { path, bindings })
+    }
+}
+
+/// Resolves a template path against the manifest directory of the calling crate.
+fn resolve_path(path: &LitStr) -> PathBuf {
+    let root = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    PathBuf::from(root).join(path.value())
+}
+
+/// Compiles an html template file, binding `name = value` pairs as template variables, into a
+/// `Result<tent::HtmlContent, tent::runtime::Error>`.
+///
+/// With the `dev` feature the file is instead interpreted by `tent::runtime` on every call,
+/// so edits show up without recompiling, and errors of the interpreter are returned. The
+/// interpreter applies `annotate` but has no components, `scoped` stylesheets or `translate`
+/// catalogs. Values are bound through `tent::runtime::ToValue` in both modes, so switching
+/// doesn't break callers.
+#[proc_macro]
+pub fn html_file(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as FileInput);
+    let path = resolve_path(&input.path);
+    let path_str = path.to_string_lossy().into_owned();
+    let names = input
+        .bindings
+        .iter()
+        .map(|binding| Ident::new(&binding.name.to_string(), proc_macro2::Span::call_site()))
+        .collect::<Vec<_>>();
+    let values = input.bindings.iter().map(|binding| &binding.value);
+
+    // Compiled in both modes, so a template failing to compile fails in either
+    let body = match html::Parsed::from_file(&path) {
+        Ok(parsed) => parsed.expand(),
+        Err(message) => {
+            let environment = html::track_environment();
+            let error = syn::Error::new(input.path.span(), message).to_compile_error();
+            return quote! {{ #environment #error }}.into();
+        }
+    };
+    if cfg!(feature = "dev") {
+        let keys = names.iter().map(|name| name.to_string());
+        return quote! {{
+            let mut context = tent::runtime::Context::new();
+            #(context.insert(#keys, tent::runtime::ToValue::to_value(&#values));)*
+            tent::runtime::Template::load(#path_str)
+                .and_then(|template| template.render(&context))
+        }}
+        .into();
+    }
+
+    quote! {{
+        #(let #names = #values;)*
+        #(tent::runtime::check_value(&#names);)*
+        Ok::<tent::HtmlContent, tent::runtime::Error>(#body)
+    }}
+    .into()
+}
+
+/// Compiles an html template file into the body of the annotated function.
+///
+/// The function parameters are the variables of the template. Other lowercase identifiers the
+/// template refers to are reported as errors.
+#[proc_macro_attribute]
+pub fn template(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let path = parse_macro_input!(attr as LitStr);
+    let item = parse_macro_input!(item as ItemFn);
+    template::expand(&path, resolve_path(&path), item.clone())
+        .unwrap_or_else(|e| {
+            // Keep the function around, so its callers don't report errors as well.
+            let environment = html::track_environment();
+            let error = e.to_compile_error();
+            let ItemFn {
+                attrs, vis, sig, ..
+            } = item;
+            quote! {
+                #environment
+                #error
+                #(#attrs)*
+                #[allow(unused_variables)]
+                #vis #sig { ::core::unreachable!() }
+            }
+        })
+        .into()
+}
+
+/// Implements `tent::Template` and `Display` for a struct, rendering the template file named by
+/// `#[template(path = "...")]` with the struct fields as its variables.
+#[proc_macro_derive(Template, attributes(template))]
+pub fn derive_template(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive::expand(input)
+        .unwrap_or_else(|e| {
+            let environment = html::track_environment();
+            let error = e.to_compile_error();
+            quote! { #environment #error }
+        })
+        .into()
+}
+
+/// Implements `tent::runtime::ToValue` for a struct, so it can be bound by `html_file!`.
+#[proc_macro_derive(ToValue)]
+pub fn derive_to_value(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    value::expand(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
 }
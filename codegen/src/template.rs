@@ -0,0 +1,59 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use quote::quote;
+use syn::{FnArg, ItemFn, LitStr, Pat};
+
+use crate::html;
+
+/// Names the function body can refer to, `self` included for methods.
+fn parameters(item: &ItemFn) -> HashSet<String> {
+    item.sig
+        .inputs
+        .iter()
+        .filter_map(|input| match input {
+            FnArg::Receiver(_) => Some("self".to_string()),
+            FnArg::Typed(typed) => match &*typed.pat {
+                Pat::Ident(pat) => Some(pat.ident.to_string()),
+                _ => None,
+            },
+        })
+        .collect()
+}
+
+pub fn expand(
+    literal: &LitStr,
+    path: PathBuf,
+    item: ItemFn,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let parsed = html::Parsed::from_file(&path).map_err(|e| syn::Error::new(literal.span(), e))?;
+
+    let known = parameters(&item);
+    let unknown = html::unknown_identifiers(&parsed.content, &known);
+    if !unknown.is_empty() {
+        let mut parameters = known.into_iter().collect::<Vec<_>>();
+        parameters.sort();
+        let message = format!(
+            "unknown identifier{} {} in template `{}`; the parameters of `{}` are: {}",
+            if unknown.len() > 1 { "s" } else { "" },
+            unknown
+                .iter()
+                .map(|name| format!("`{}`", name))
+                .collect::<Vec<_>>()
+                .join(", "),
+            literal.value(),
+            item.sig.ident,
+            parameters.join(", "),
+        );
+        return Err(syn::Error::new(literal.span(), message));
+    }
+
+    let ItemFn {
+        attrs, vis, sig, ..
+    } = item;
+    let body = parsed.expand();
+    Ok(quote! {
+        #(#attrs)*
+        #vis #sig #body
+    })
+}
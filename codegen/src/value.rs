@@ -0,0 +1,47 @@
+use quote::quote;
+use syn::{Data, DeriveInput, Fields};
+
+/// Implements `tent::runtime::ToValue` for a struct, as a map of its fields.
+pub fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => fields
+                .named
+                .iter()
+                .filter_map(|field| field.ident.as_ref())
+                .collect::<Vec<_>>(),
+            Fields::Unit => vec![],
+            Fields::Unnamed(_) => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "values need named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "values can only be derived for structs",
+            ))
+        }
+    };
+    let keys = fields.iter().map(|ident| {
+        let name = ident.to_string();
+        name.strip_prefix("r#").unwrap_or(&name).to_string()
+    });
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics tent::runtime::ToValue for #name #ty_generics #where_clause {
+            fn to_value(&self) -> tent::runtime::Value {
+                let mut fields = std::collections::BTreeMap::new();
+                #(fields.insert(
+                    #keys.to_string(),
+                    tent::runtime::ToValue::to_value(&self.#fields),
+                );)*
+                tent::runtime::Value::Map(fields)
+            }
+        }
+    })
+}
@@ -0,0 +1,110 @@
+use std::fmt;
+
+use tent_syntax::html::{Content, Parser};
+
+use crate::html::value;
+
+/// Rust code building the `tent::XmlContent` described by a parsed document.
+pub struct Expansion<'a>(pub &'a Content);
+
+impl fmt::Display for Expansion<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Content::Element {
+                name,
+                properties,
+                contents,
+                ..
+            } => {
+                let properties = properties
+                    .iter()
+                    .map(|(name, value)| {
+                        format!(
+                            "({:?}.to_string(),{}.to_string()),",
+                            name,
+                            self::value(value)
+                        )
+                    })
+                    .collect::<String>();
+                if let Some(target) = name.strip_prefix('?') {
+                    return write!(
+                        f,
+                        "tent::XmlContent::Instruction {{ target: \"{}\".to_string(), properties: vec![{}] }}",
+                        target, properties
+                    );
+                }
+                write!(
+                    f,
+                    "tent::XmlContent::Element {{ name: \"{}\".to_string(), properties: vec![{}], contents: vec![",
+                    name, properties
+                )?;
+                for content in contents.iter() {
+                    write!(f, "{},", Expansion(content))?;
+                }
+                write!(f, "] }}")
+            }
+            Content::Text(text) if text.starts_with('{') => {
+                write!(f, "tent::IntoXml::into_xml({})", value(text))
+            }
+            Content::Text(text) => write!(f, "tent::XmlContent::Text({}.to_string())", text),
+            Content::CData(text) => {
+                write!(f, "tent::XmlContent::CData({}.to_string())", value(text))
+            }
+            Content::Component { .. } | Content::Block { .. } | Content::Extends { .. } => {
+                unreachable!("Rejected by `check`")
+            }
+        }
+    }
+}
+
+/// Rejects the parts of the template syntax that only make sense in html.
+fn check(content: &Content) -> Result<(), String> {
+    match content {
+        Content::Element {
+            name,
+            class_names,
+            contents,
+            ..
+        } => {
+            if !class_names.is_empty() {
+                return Err(format!(
+                    "`{}` has class names, which `xml!` doesn't support; use a `class` attribute",
+                    name
+                ));
+            }
+            if name.starts_with('?') && !contents.is_empty() {
+                return Err(format!(
+                    "processing instruction `{}` can't have contents",
+                    name
+                ));
+            }
+            contents.iter().try_for_each(check)
+        }
+        Content::Text(_) | Content::CData(_) => Ok(()),
+        Content::Component { name, .. } => {
+            Err(format!("component `{}` can't be used in `xml!`", name))
+        }
+        Content::Block { .. } | Content::Extends { .. } => {
+            Err("layouts can't be used in `xml!`".to_string())
+        }
+    }
+}
+
+/// Builds the code for an xml document, which may have several top-level nodes.
+pub fn expand(source: &str) -> Result<proc_macro2::TokenStream, String> {
+    let nodes = Parser::from_xml_str(source)
+        .and_then(Parser::build_xml)
+        .map_err(|e| format!("cannot parse template: {}", e))?;
+    nodes.iter().try_for_each(check)?;
+    let code = match nodes.as_slice() {
+        [node] => Expansion(node).to_string(),
+        nodes => format!(
+            "tent::XmlContent::Fragment(vec![{}])",
+            nodes
+                .iter()
+                .map(|node| format!("{},", Expansion(node)))
+                .collect::<String>()
+        ),
+    };
+    Ok(code.parse().unwrap())
+}
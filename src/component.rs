@@ -0,0 +1,11 @@
+use crate::HtmlContent;
+
+/// A reusable piece of markup, called as `@Name prop={value}` from `html!`.
+///
+/// The fields of the implementing struct are its props, so `html!` checks their names and
+/// types at compile time. Each prop value is converted with `Into`. Lines indented below the
+/// call are passed in a `children: HtmlContent` field, which a component taking children
+/// declares like any other prop.
+pub trait Component {
+    fn render(self) -> HtmlContent;
+}
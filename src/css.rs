@@ -34,3 +34,38 @@ impl fmt::Display for Content {
         Ok(())
     }
 }
+
+/// Class names of a scoped stylesheet, mapped to their hashed names.
+#[derive(Debug)]
+pub struct Scope {
+    pub classes: Vec<(String, String)>,
+}
+
+impl Scope {
+    /// The scoped name of a class, or the name itself for classes the stylesheet doesn't define.
+    pub fn class<'a>(&'a self, name: &'a str) -> &'a str {
+        self.classes
+            .iter()
+            .find(|(class, _)| class == name)
+            .map_or(name, |(_, scoped)| scoped.as_str())
+    }
+}
+
+/// A stylesheet made by `css!(scoped, ...)`, along with the class names it hashed.
+#[derive(Debug)]
+pub struct ScopedContent {
+    pub content: Content,
+    pub scope: Scope,
+}
+
+impl ScopedContent {
+    pub fn class<'a>(&'a self, name: &'a str) -> &'a str {
+        self.scope.class(name)
+    }
+}
+
+impl fmt::Display for ScopedContent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.content.fmt(f)
+    }
+}
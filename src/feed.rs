@@ -0,0 +1,229 @@
+//! Atom 1.0 and RSS 2.0 feeds, rendered as `XmlContent`.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{HtmlContent, XmlContent};
+
+/// A point in time, kept in UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date {
+    timestamp: i64,
+}
+
+const WEEKDAYS: [&str; 7] = ["Thu", "Fri", "Sat", "Sun", "Mon", "Tue", "Wed"];
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+impl Date {
+    /// The date given as seconds since the Unix epoch.
+    pub fn from_timestamp(timestamp: i64) -> Self {
+        Self { timestamp }
+    }
+
+    /// Year, month, day, hours, minutes and seconds, with months and days counted from 1.
+    fn civil(&self) -> (i64, usize, i64, i64, i64, i64) {
+        let days = self.timestamp.div_euclid(86400);
+        let seconds = self.timestamp.rem_euclid(86400);
+        // Days to civil date, from Howard Hinnant's `civil_from_days`
+        let z = days + 719468;
+        let era = z.div_euclid(146097);
+        let doe = z.rem_euclid(146097);
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + i64::from(month <= 2);
+        (
+            year,
+            month as usize,
+            day,
+            seconds / 3600,
+            seconds % 3600 / 60,
+            seconds % 60,
+        )
+    }
+
+    /// Formats the date like `2024-03-01T09:30:00Z`, as Atom feeds use.
+    pub fn to_rfc3339(&self) -> String {
+        let (year, month, day, hours, minutes, seconds) = self.civil();
+        format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+            year, month, day, hours, minutes, seconds
+        )
+    }
+
+    /// Formats the date like `Fri, 01 Mar 2024 09:30:00 +0000`, as RSS feeds use.
+    pub fn to_rfc822(&self) -> String {
+        let (year, month, day, hours, minutes, seconds) = self.civil();
+        let weekday = WEEKDAYS[self.timestamp.div_euclid(86400).rem_euclid(7) as usize];
+        format!(
+            "{}, {:02} {} {:04} {:02}:{:02}:{:02} +0000",
+            weekday,
+            day,
+            MONTHS[month - 1],
+            year,
+            hours,
+            minutes,
+            seconds
+        )
+    }
+}
+
+impl From<SystemTime> for Date {
+    fn from(time: SystemTime) -> Self {
+        let timestamp = match time.duration_since(UNIX_EPOCH) {
+            Ok(duration) => duration.as_secs() as i64,
+            Err(e) => -(e.duration().as_secs() as i64),
+        };
+        Self { timestamp }
+    }
+}
+
+/// A feed, rendered with `to_atom` or `to_rss`.
+#[derive(Debug)]
+pub struct Feed {
+    pub title: String,
+    /// Address of the site the feed is about.
+    pub link: String,
+    /// A permanent, unique identifier, usually the address of the feed itself.
+    pub id: String,
+    pub description: String,
+    pub updated: Date,
+    /// Atom needs an author for every entry, so entries without one of their own fall back to
+    /// this, or to the title of the feed when it's missing too.
+    pub author: Option<String>,
+    pub entries: Vec<Entry>,
+}
+
+#[derive(Debug)]
+pub struct Entry {
+    pub title: String,
+    pub link: String,
+    pub id: String,
+    pub updated: Date,
+    /// Markup describing the entry, sent escaped as feeds expect.
+    pub summary: Option<HtmlContent>,
+    pub author: Option<String>,
+}
+
+fn element(name: &str, properties: &[(&str, &str)], contents: Vec<XmlContent>) -> XmlContent {
+    XmlContent::new_element(
+        name.to_string(),
+        properties
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect(),
+        contents,
+    )
+}
+
+fn text(name: &str, text: &str) -> XmlContent {
+    element(name, &[], vec![XmlContent::new_text(text.to_string())])
+}
+
+fn declaration() -> XmlContent {
+    XmlContent::Instruction {
+        target: "xml".to_string(),
+        properties: vec![
+            ("version".to_string(), "1.0".to_string()),
+            ("encoding".to_string(), "utf-8".to_string()),
+        ],
+    }
+}
+
+impl Feed {
+    /// Renders the feed as an Atom 1.0 document.
+    pub fn to_atom(&self) -> XmlContent {
+        let mut contents = vec![
+            text("title", &self.title),
+            element("link", &[("href", &self.link)], vec![]),
+            text("id", &self.id),
+            text("updated", &self.updated.to_rfc3339()),
+        ];
+        if !self.description.is_empty() {
+            contents.push(text("subtitle", &self.description));
+        }
+        let anonymous = self.entries.iter().any(|entry| entry.author.is_none());
+        match &self.author {
+            Some(author) => contents.push(element("author", &[], vec![text("name", author)])),
+            None if anonymous => {
+                contents.push(element("author", &[], vec![text("name", &self.title)]))
+            }
+            None => (),
+        }
+        contents.extend(self.entries.iter().map(Entry::to_atom));
+        XmlContent::new_fragment(vec![
+            declaration(),
+            element(
+                "feed",
+                &[("xmlns", "http://www.w3.org/2005/Atom")],
+                contents,
+            ),
+        ])
+    }
+
+    /// Renders the feed as an RSS 2.0 document.
+    pub fn to_rss(&self) -> XmlContent {
+        let mut contents = vec![
+            text("title", &self.title),
+            text("link", &self.link),
+            text("description", &self.description),
+            text("lastBuildDate", &self.updated.to_rfc822()),
+        ];
+        contents.extend(self.entries.iter().map(Entry::to_rss));
+        XmlContent::new_fragment(vec![
+            declaration(),
+            element(
+                "rss",
+                &[("version", "2.0")],
+                vec![element("channel", &[], contents)],
+            ),
+        ])
+    }
+}
+
+impl Entry {
+    fn to_atom(&self) -> XmlContent {
+        let mut contents = vec![
+            text("title", &self.title),
+            element("link", &[("href", &self.link)], vec![]),
+            text("id", &self.id),
+            text("updated", &self.updated.to_rfc3339()),
+        ];
+        if let Some(author) = &self.author {
+            contents.push(element("author", &[], vec![text("name", author)]));
+        }
+        if let Some(summary) = &self.summary {
+            contents.push(element(
+                "summary",
+                &[("type", "html")],
+                vec![XmlContent::new_text(summary.to_string())],
+            ));
+        }
+        element("entry", &[], contents)
+    }
+
+    fn to_rss(&self) -> XmlContent {
+        let permalink = if self.id == self.link {
+            "true"
+        } else {
+            "false"
+        };
+        let mut contents = vec![
+            text("title", &self.title),
+            text("link", &self.link),
+            element(
+                "guid",
+                &[("isPermaLink", permalink)],
+                vec![XmlContent::new_text(self.id.clone())],
+            ),
+            text("pubDate", &self.updated.to_rfc822()),
+        ];
+        if let Some(summary) = &self.summary {
+            contents.push(text("description", &summary.to_string()));
+        }
+        element("item", &[], contents)
+    }
+}
@@ -9,6 +9,7 @@ pub enum Content {
         contents: Vec<Content>,
     },
     Text(String),
+    Fragment(Vec<Content>),
 }
 
 impl fmt::Display for Content {
@@ -20,26 +21,42 @@ impl fmt::Display for Content {
                 properties,
                 contents,
             } => {
-                if class_names.is_empty() {
-                    write!(f, "<{}", name)?;
-                } else {
-                    write!(f, "<{} class=\"{}\"", name, class_names.join(" "))?;
-                }
-                for (name, value) in properties {
-                    write!(f, " {}=\"{}\"", name, value)?;
-                }
-                // TODO: Implement empty tag
-                write!(f, ">")?;
+                write_open_tag(f, name, class_names, properties)?;
                 for content in contents.iter() {
                     content.fmt(f)?;
                 }
                 write!(f, "</{}>", name)
             }
             Content::Text(text) => write!(f, "{}", text),
+            Content::Fragment(contents) => {
+                for content in contents.iter() {
+                    content.fmt(f)?;
+                }
+                Ok(())
+            }
         }
     }
 }
 
+/// Writes the start tag of an element.
+pub(crate) fn write_open_tag(
+    f: &mut impl fmt::Write,
+    name: &str,
+    class_names: &[String],
+    properties: &[(String, String)],
+) -> fmt::Result {
+    if class_names.is_empty() {
+        write!(f, "<{}", name)?;
+    } else {
+        write!(f, "<{} class=\"{}\"", name, class_names.join(" "))?;
+    }
+    for (name, value) in properties {
+        write!(f, " {}=\"{}\"", name, value)?;
+    }
+    // TODO: Implement empty tag
+    write!(f, ">")
+}
+
 impl Content {
     pub fn new_element(
         name: String,
@@ -58,4 +75,8 @@ impl Content {
     pub fn new_text(text: String) -> Self {
         Self::Text(text)
     }
+
+    pub fn new_fragment(contents: Vec<Content>) -> Self {
+        Self::Fragment(contents)
+    }
 }
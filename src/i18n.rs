@@ -0,0 +1,27 @@
+use std::collections::HashMap;
+
+/// Looks up the messages of `{t!("key", name = value)}` in templates with a `translate` line
+/// naming the translator, like `translate tr`.
+///
+/// Arguments are given as text, to be placed in the message.
+pub trait Translator {
+    fn translate(&self, key: &str, args: &[(&str, String)]) -> String;
+}
+
+impl<T: Translator + ?Sized> Translator for &T {
+    fn translate(&self, key: &str, args: &[(&str, String)]) -> String {
+        (**self).translate(key, args)
+    }
+}
+
+/// Messages by key, with `{name}` replaced by the argument of the same name. Keys without a
+/// message are given back as they are.
+impl Translator for HashMap<String, String> {
+    fn translate(&self, key: &str, args: &[(&str, String)]) -> String {
+        let mut message = self.get(key).map_or(key, String::as_str).to_string();
+        for (name, value) in args {
+            message = message.replace(&format!("{{{}}}", name), value);
+        }
+        message
+    }
+}
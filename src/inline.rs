@@ -0,0 +1,309 @@
+use crate::{CssContent, CssItem, HtmlContent};
+
+/// Moves the rules of a stylesheet into the `style` attributes of the elements they match, for
+/// HTML email.
+///
+/// Declarations are applied in order of specificity and then source order, and an existing
+/// `style` attribute keeps precedence. Rules that can't be inlined, like `@media` rules or
+/// selectors with pseudo-classes, are kept in a `<style>` element inside `head`, or before the
+/// content when there is no `head`.
+pub fn inline_css(html: &HtmlContent, css: &CssContent) -> HtmlContent {
+    let mut rules = vec![];
+    let mut retained = String::new();
+    for (order, item) in css.items.iter().enumerate() {
+        let (name, children) = match item {
+            CssItem::Node { name, children } => (name, children),
+            CssItem::Declaration(..) => continue,
+        };
+        let declarations = children
+            .iter()
+            .filter_map(|child| match child {
+                CssItem::Declaration(key, value) => Some((key.as_str(), value.as_str())),
+                CssItem::Node { .. } => None,
+            })
+            .collect::<Vec<_>>();
+        if name.starts_with('@') || declarations.len() != children.len() {
+            retained.push_str(&item.to_string());
+            continue;
+        }
+        let mut inlined_all = true;
+        for selector in name.split(',') {
+            match Selector::parse(selector) {
+                Some(selector) => rules.push(Rule {
+                    specificity: selector.specificity(),
+                    order,
+                    selector,
+                    declarations: declarations.clone(),
+                }),
+                None => inlined_all = false,
+            }
+        }
+        if !inlined_all {
+            retained.push_str(&item.to_string());
+        }
+    }
+    rules.sort_by_key(|rule| (rule.specificity, rule.order));
+
+    let mut content = inline(html, &rules, &mut vec![]);
+    if !retained.is_empty() {
+        let style = HtmlContent::new_element(
+            "style".to_string(),
+            vec![],
+            vec![],
+            vec![HtmlContent::new_text(retained)],
+        );
+        if let Err(style) = insert_into_head(&mut content, style) {
+            content = HtmlContent::new_fragment(vec![style, content]);
+        }
+    }
+    content
+}
+
+struct Rule<'a> {
+    selector: Selector,
+    specificity: (usize, usize, usize),
+    order: usize,
+    declarations: Vec<(&'a str, &'a str)>,
+}
+
+/// The parts of an element a selector can match.
+struct Node<'a> {
+    name: &'a str,
+    class_names: &'a [String],
+    id: Option<&'a str>,
+}
+
+impl Node<'_> {
+    fn has_class(&self, class: &str) -> bool {
+        self.class_names
+            .iter()
+            .flat_map(|names| names.split_whitespace())
+            .any(|name| name == class)
+    }
+}
+
+#[derive(Default)]
+struct Compound {
+    name: Option<String>,
+    classes: Vec<String>,
+    ids: Vec<String>,
+}
+
+impl Compound {
+    fn parse(source: &str) -> Option<Self> {
+        fn is_name_char(c: char) -> bool {
+            c.is_alphanumeric() || c == '-' || c == '_'
+        }
+
+        let mut res = Compound::default();
+        let mut rest = source;
+        if let Some(tail) = rest.strip_prefix('*') {
+            rest = tail;
+        } else {
+            let end = rest.find(|c| !is_name_char(c)).unwrap_or(rest.len());
+            if end > 0 {
+                res.name = Some(rest[..end].to_string());
+                rest = &rest[end..];
+            }
+        }
+        while let Some(prefix) = rest.chars().next() {
+            let tail = &rest[1..];
+            let end = tail.find(|c| !is_name_char(c)).unwrap_or(tail.len());
+            if end == 0 {
+                return None;
+            }
+            match prefix {
+                '.' => res.classes.push(tail[..end].to_string()),
+                '#' => res.ids.push(tail[..end].to_string()),
+                _ => return None,
+            }
+            rest = &tail[end..];
+        }
+        Some(res)
+    }
+
+    fn matches(&self, node: &Node) -> bool {
+        self.name
+            .as_ref()
+            .is_none_or(|name| name.eq_ignore_ascii_case(node.name))
+            && self.classes.iter().all(|class| node.has_class(class))
+            && self.ids.iter().all(|id| node.id == Some(id.as_str()))
+    }
+}
+
+enum Combinator {
+    Descendant,
+    Child,
+}
+
+/// A selector made of compound selectors joined by descendant or child combinators.
+struct Selector {
+    compounds: Vec<Compound>,
+    combinators: Vec<Combinator>,
+}
+
+impl Selector {
+    fn parse(source: &str) -> Option<Self> {
+        let mut compounds = vec![];
+        let mut combinators = vec![];
+        let mut child = false;
+        for part in source.replace('>', " > ").split_whitespace() {
+            if part == ">" {
+                if compounds.is_empty() || child {
+                    return None;
+                }
+                child = true;
+                continue;
+            }
+            if !compounds.is_empty() {
+                combinators.push(if child {
+                    Combinator::Child
+                } else {
+                    Combinator::Descendant
+                });
+            }
+            child = false;
+            compounds.push(Compound::parse(part)?);
+        }
+        if compounds.is_empty() || child {
+            return None;
+        }
+        Some(Selector {
+            compounds,
+            combinators,
+        })
+    }
+
+    fn specificity(&self) -> (usize, usize, usize) {
+        self.compounds
+            .iter()
+            .fold((0, 0, 0), |(a, b, c), compound| {
+                (
+                    a + compound.ids.len(),
+                    b + compound.classes.len(),
+                    c + compound.name.is_some() as usize,
+                )
+            })
+    }
+
+    fn matches(&self, node: &Node, ancestors: &[Node]) -> bool {
+        let last = self.compounds.len() - 1;
+        self.compounds[last].matches(node) && self.matches_ancestors(last, ancestors)
+    }
+
+    /// Whether the compounds before `index` match the ancestors, innermost last.
+    fn matches_ancestors(&self, index: usize, ancestors: &[Node]) -> bool {
+        if index == 0 {
+            return true;
+        }
+        let compound = &self.compounds[index - 1];
+        match self.combinators[index - 1] {
+            Combinator::Child => match ancestors.split_last() {
+                Some((parent, rest)) => {
+                    compound.matches(parent) && self.matches_ancestors(index - 1, rest)
+                }
+                None => false,
+            },
+            Combinator::Descendant => (0..ancestors.len()).rev().any(|i| {
+                compound.matches(&ancestors[i])
+                    && self.matches_ancestors(index - 1, &ancestors[..i])
+            }),
+        }
+    }
+}
+
+fn inline<'a>(
+    content: &'a HtmlContent,
+    rules: &[Rule],
+    ancestors: &mut Vec<Node<'a>>,
+) -> HtmlContent {
+    match content {
+        HtmlContent::Element {
+            name,
+            class_names,
+            properties,
+            contents,
+        } => {
+            let node = Node {
+                name,
+                class_names,
+                id: properties
+                    .iter()
+                    .find(|(key, _)| key == "id")
+                    .map(|(_, value)| value.as_str()),
+            };
+
+            let mut declarations: Vec<(String, String)> = vec![];
+            let mut declare = |key: &str, value: &str| match declarations
+                .iter_mut()
+                .find(|(name, _)| name == key)
+            {
+                Some(declaration) => declaration.1 = value.to_string(),
+                None => declarations.push((key.to_string(), value.to_string())),
+            };
+            for rule in rules {
+                if rule.selector.matches(&node, ancestors) {
+                    for (key, value) in rule.declarations.iter() {
+                        declare(key, value);
+                    }
+                }
+            }
+            let mut properties = properties.clone();
+            let style = properties.iter().position(|(key, _)| key == "style");
+            if let Some(index) = style {
+                for declaration in properties[index].1.split(';') {
+                    if let Some((key, value)) = declaration.split_once(':') {
+                        declare(key.trim(), value.trim());
+                    }
+                }
+            }
+            if !declarations.is_empty() {
+                let style_value = declarations
+                    .iter()
+                    .map(|(key, value)| format!("{}: {};", key, value))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                match style {
+                    Some(index) => properties[index].1 = style_value,
+                    None => properties.push(("style".to_string(), style_value)),
+                }
+            }
+
+            ancestors.push(node);
+            let contents = contents
+                .iter()
+                .map(|content| inline(content, rules, ancestors))
+                .collect();
+            ancestors.pop();
+            HtmlContent::new_element(name.clone(), class_names.clone(), properties, contents)
+        }
+        HtmlContent::Text(text) => HtmlContent::new_text(text.clone()),
+        HtmlContent::Fragment(contents) => HtmlContent::new_fragment(
+            contents
+                .iter()
+                .map(|content| inline(content, rules, ancestors))
+                .collect(),
+        ),
+    }
+}
+
+/// Appends `style` to the first `head` element, giving it back when there is none.
+fn insert_into_head(content: &mut HtmlContent, style: HtmlContent) -> Result<(), HtmlContent> {
+    match content {
+        HtmlContent::Element { name, contents, .. } if name == "head" => {
+            contents.push(style);
+            Ok(())
+        }
+        HtmlContent::Element { contents, .. } | HtmlContent::Fragment(contents) => {
+            let mut style = style;
+            for content in contents.iter_mut() {
+                match insert_into_head(content, style) {
+                    Ok(()) => return Ok(()),
+                    Err(rest) => style = rest,
+                }
+            }
+            Err(style)
+        }
+        HtmlContent::Text(_) => Err(style),
+    }
+}
@@ -1,7 +1,29 @@
+mod component;
 mod css;
+pub mod feed;
 mod html;
+mod i18n;
+mod inline;
+#[cfg(any(feature = "axum", feature = "actix-web"))]
+mod response;
+pub mod runtime;
+#[cfg(feature = "async")]
+mod stream;
+mod template;
+mod xml;
 
+pub use component::Component;
 pub use css::Content as CssContent;
 pub use css::Item as CssItem;
+pub use css::Scope as CssScope;
+pub use css::ScopedContent as ScopedCssContent;
 pub use html::Content as HtmlContent;
-pub use tent_codegen::{css, html};
+pub use i18n::Translator;
+pub use inline::inline_css;
+#[cfg(feature = "async")]
+pub use stream::Content as AsyncHtmlContent;
+pub use template::Template;
+pub use tent_codegen::{css, html, html_file, template, xml, Template};
+pub use tent_syntax::{check, convert, format};
+pub use xml::Content as XmlContent;
+pub use xml::IntoXml;
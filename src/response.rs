@@ -0,0 +1,61 @@
+//! Web framework responses for rendered content, behind the `axum` and `actix-web` features.
+
+#[cfg(feature = "axum")]
+mod axum_impl {
+    use axum::http::header;
+    use axum::response::{IntoResponse, Response};
+
+    use crate::{CssContent, HtmlContent};
+
+    impl IntoResponse for HtmlContent {
+        fn into_response(self) -> Response {
+            (
+                [(header::CONTENT_TYPE, super::HTML_CONTENT_TYPE)],
+                self.to_string(),
+            )
+                .into_response()
+        }
+    }
+
+    impl IntoResponse for CssContent {
+        fn into_response(self) -> Response {
+            (
+                [(header::CONTENT_TYPE, super::CSS_CONTENT_TYPE)],
+                self.to_string(),
+            )
+                .into_response()
+        }
+    }
+}
+
+#[cfg(feature = "actix-web")]
+mod actix_impl {
+    use actix_web::body::BoxBody;
+    use actix_web::http::header;
+    use actix_web::{HttpRequest, HttpResponse, Responder};
+
+    use crate::{CssContent, HtmlContent};
+
+    impl Responder for HtmlContent {
+        type Body = BoxBody;
+
+        fn respond_to(self, _: &HttpRequest) -> HttpResponse {
+            HttpResponse::Ok()
+                .insert_header((header::CONTENT_TYPE, super::HTML_CONTENT_TYPE))
+                .body(self.to_string())
+        }
+    }
+
+    impl Responder for CssContent {
+        type Body = BoxBody;
+
+        fn respond_to(self, _: &HttpRequest) -> HttpResponse {
+            HttpResponse::Ok()
+                .insert_header((header::CONTENT_TYPE, super::CSS_CONTENT_TYPE))
+                .body(self.to_string())
+        }
+    }
+}
+
+const HTML_CONTENT_TYPE: &str = "text/html; charset=utf-8";
+const CSS_CONTENT_TYPE: &str = "text/css";
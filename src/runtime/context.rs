@@ -0,0 +1,168 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+/// A value a template can refer to by name.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Text(String),
+    List(Vec<Value>),
+    Map(BTreeMap<String, Value>),
+}
+
+impl Value {
+    /// Follows a `field` of a map or an index of a list.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Text(_) => None,
+            Value::List(values) => values.get(key.parse::<usize>().ok()?),
+            Value::Map(values) => values.get(key),
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Text(text) => write!(f, "{}", text),
+            Value::List(values) => {
+                for value in values.iter() {
+                    write!(f, "{}", value)?;
+                }
+                Ok(())
+            }
+            Value::Map(_) => Ok(()),
+        }
+    }
+}
+
+macro_rules! impl_from_display {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Value {
+                fn from(value: $ty) -> Self {
+                    Value::Text(value.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_display!(
+    &str, String, &String, char, bool, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128,
+    usize, f32, f64
+);
+
+impl<T: Into<Value>> From<Vec<T>> for Value {
+    fn from(values: Vec<T>) -> Self {
+        Value::List(values.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<K: Into<String>, V: Into<Value>> From<BTreeMap<K, V>> for Value {
+    fn from(values: BTreeMap<K, V>) -> Self {
+        Value::Map(
+            values
+                .into_iter()
+                .map(|(key, value)| (key.into(), value.into()))
+                .collect(),
+        )
+    }
+}
+
+impl<K: Into<String>, V: Into<Value>> From<HashMap<K, V>> for Value {
+    fn from(values: HashMap<K, V>) -> Self {
+        Value::Map(
+            values
+                .into_iter()
+                .map(|(key, value)| (key.into(), value.into()))
+                .collect(),
+        )
+    }
+}
+
+/// A value `html_file!` can bind, whether the template is compiled or, with the `dev` feature,
+/// interpreted. Structs implement it with `#[derive(tent::runtime::ToValue)]`.
+pub trait ToValue {
+    fn to_value(&self) -> Value;
+}
+
+macro_rules! impl_to_value_display {
+    ($($ty:ty),*) => {
+        $(
+            impl ToValue for $ty {
+                fn to_value(&self) -> Value {
+                    Value::Text(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_to_value_display!(
+    str, String, char, bool, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32,
+    f64
+);
+
+impl ToValue for Value {
+    fn to_value(&self) -> Value {
+        self.clone()
+    }
+}
+
+impl<T: ToValue + ?Sized> ToValue for &T {
+    fn to_value(&self) -> Value {
+        (**self).to_value()
+    }
+}
+
+impl<T: ToValue> ToValue for [T] {
+    fn to_value(&self) -> Value {
+        Value::List(self.iter().map(ToValue::to_value).collect())
+    }
+}
+
+impl<T: ToValue> ToValue for Vec<T> {
+    fn to_value(&self) -> Value {
+        self.as_slice().to_value()
+    }
+}
+
+impl<K: ToString, V: ToValue> ToValue for BTreeMap<K, V> {
+    fn to_value(&self) -> Value {
+        Value::Map(
+            self.iter()
+                .map(|(key, value)| (key.to_string(), value.to_value()))
+                .collect(),
+        )
+    }
+}
+
+impl<K: ToString, V: ToValue> ToValue for HashMap<K, V> {
+    fn to_value(&self) -> Value {
+        Value::Map(
+            self.iter()
+                .map(|(key, value)| (key.to_string(), value.to_value()))
+                .collect(),
+        )
+    }
+}
+
+/// Named values available to an interpreted template.
+#[derive(Debug, Default, Clone)]
+pub struct Context {
+    values: HashMap<String, Value>,
+}
+
+impl Context {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, name: impl Into<String>, value: impl Into<Value>) {
+        self.values.insert(name.into(), value.into());
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.values.get(name)
+    }
+}
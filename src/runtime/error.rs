@@ -0,0 +1,52 @@
+use std::{fmt, io};
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Parse,
+    /// A template the parser rejects, like one whose indentation mixes tabs and spaces.
+    Syntax(String),
+    UndefinedVariable(String),
+    UnsupportedExpression(String),
+    UnsupportedComponent(String),
+    /// A directive like `scoped` or `translate` that only the macros implement.
+    UnsupportedDirective(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "cannot read template: {}", e),
+            Error::Parse => write!(f, "cannot parse template"),
+            Error::Syntax(message) => write!(f, "cannot parse template: {}", message),
+            Error::UndefinedVariable(name) => write!(f, "undefined variable `{}`", name),
+            Error::UnsupportedExpression(expr) => {
+                write!(
+                    f,
+                    "unsupported expression `{}` in interpreted template",
+                    expr
+                )
+            }
+            Error::UnsupportedComponent(name) => {
+                write!(f, "component `{}` can not be interpreted", name)
+            }
+            Error::UnsupportedDirective(name) => {
+                write!(f, "directive `{}` can not be interpreted", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<String> for Error {
+    fn from(message: String) -> Self {
+        Error::Syntax(message)
+    }
+}
@@ -0,0 +1,121 @@
+use tent_syntax::css;
+use tent_syntax::html::{self, SOURCE_ATTRIBUTE};
+use tent_syntax::util::unquote;
+
+use super::{Context, Error, Value};
+use crate::{CssContent, CssItem, HtmlContent};
+
+/// Evaluates a literal, or a `{path.to.value}` group looked up in the context.
+fn value(source: &str, context: &Context) -> Result<String, Error> {
+    let expr = match source.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+        Some(inner) => inner.trim(),
+        None => return Ok(unquote(source)),
+    };
+    if expr.starts_with('"') || expr.starts_with("r\"") || expr.starts_with("r#") {
+        return Ok(unquote(expr));
+    }
+
+    let path: String = expr.chars().filter(|c| !c.is_whitespace()).collect();
+    let path = path.strip_suffix(".to_string()").unwrap_or(&path);
+    let mut segments = path.split('.');
+    let name = segments.next().unwrap_or_default();
+    let is_path = path.split('.').all(|segment| {
+        !segment.is_empty() && segment.chars().all(|c| c == '_' || c.is_alphanumeric())
+    });
+    if !is_path {
+        return Err(Error::UnsupportedExpression(expr.to_string()));
+    }
+
+    let mut current = context
+        .get(name)
+        .ok_or_else(|| Error::UndefinedVariable(name.to_string()))?;
+    for segment in segments {
+        current = current
+            .get(segment)
+            .ok_or_else(|| Error::UndefinedVariable(path.to_string()))?;
+    }
+    match current {
+        Value::Map(_) => Err(Error::UnsupportedExpression(expr.to_string())),
+        value => Ok(value.to_string()),
+    }
+}
+
+/// Evaluates a template. With `comments`, source locations are written as comments in front of
+/// their elements rather than as attributes.
+pub fn html(
+    content: &html::Content,
+    context: &Context,
+    comments: bool,
+) -> Result<HtmlContent, Error> {
+    match content {
+        html::Content::Element {
+            name,
+            class_names,
+            properties,
+            contents,
+        } => {
+            let source = properties
+                .iter()
+                .find(|(name, _)| name == SOURCE_ATTRIBUTE)
+                .filter(|_| comments);
+            let element = HtmlContent::Element {
+                name: name.clone(),
+                class_names: class_names
+                    .iter()
+                    .map(|class_name| match class_name.starts_with('{') {
+                        true => value(class_name, context),
+                        false => Ok(class_name.clone()),
+                    })
+                    .collect::<Result<_, Error>>()?,
+                properties: properties
+                    .iter()
+                    .filter(|(name, _)| source.is_none() || name != SOURCE_ATTRIBUTE)
+                    .map(|(name, source)| Ok((name.clone(), value(source, context)?)))
+                    .collect::<Result<_, Error>>()?,
+                contents: contents
+                    .iter()
+                    .map(|content| html(content, context, comments))
+                    .collect::<Result<_, Error>>()?,
+            };
+            Ok(match source {
+                Some((_, source)) => HtmlContent::Fragment(vec![
+                    HtmlContent::Text(format!("<!-- tent: {} -->", unquote(source))),
+                    element,
+                ]),
+                None => element,
+            })
+        }
+        html::Content::Text(source) => Ok(HtmlContent::Text(value(source, context)?)),
+        html::Content::Component { name, .. } => Err(Error::UnsupportedComponent(name.clone())),
+        // Only `xml!` has CDATA sections
+        html::Content::CData(_) => Err(Error::Parse),
+        html::Content::Block { .. } | html::Content::Extends { .. } => {
+            unreachable!("Layouts are resolved when loading")
+        }
+    }
+}
+
+fn css_item(item: &css::Item, context: &Context) -> Result<CssItem, Error> {
+    match item {
+        css::Item::Node { name, children } => Ok(CssItem::Node {
+            name: name.clone(),
+            children: children
+                .iter()
+                .map(|child| css_item(child, context))
+                .collect::<Result<_, Error>>()?,
+        }),
+        css::Item::Declaration(key, source) => {
+            Ok(CssItem::Declaration(key.clone(), value(source, context)?))
+        }
+    }
+}
+
+pub fn css(content: &css::Content, context: &Context) -> Result<CssContent, Error> {
+    Ok(CssContent {
+        items: content
+            .items
+            .iter()
+            .map(|item| css_item(item, context))
+            .collect::<Result<_, Error>>()?,
+    })
+}
@@ -0,0 +1,164 @@
+//! Interprets template files at runtime, so they can be edited without recompiling.
+
+mod context;
+mod error;
+mod eval;
+
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use tent_syntax::css;
+use tent_syntax::html::{self, Annotation};
+
+use crate::{CssContent, HtmlContent};
+
+pub use context::{Context, ToValue, Value};
+pub use error::Error;
+pub use tent_codegen::ToValue;
+
+/// Bounds the bindings of a compiled `html_file!` like the interpreter does, so turning the `dev`
+/// feature on doesn't break its callers.
+#[doc(hidden)]
+pub fn check_value<T: ToValue + ?Sized>(_: &T) {}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|meta| meta.modified())
+        .ok()
+}
+
+/// An html template parsed at runtime.
+///
+/// Components, `scoped` stylesheets and `translate` lookups need the compiler, and templates
+/// using them give an error.
+#[derive(Debug)]
+pub struct Template {
+    path: Option<PathBuf>,
+    modified: Option<SystemTime>,
+    content: html::Content,
+    annotation: Option<Annotation>,
+}
+
+/// A template path as shown in annotations, relative to the working directory.
+fn display_path(path: &Path) -> String {
+    let dir = std::env::current_dir().unwrap_or_default();
+    path.strip_prefix(&dir)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .into_owned()
+}
+
+impl Template {
+    /// Parses a template, resolving the layouts it extends relative to `base`. Elements are
+    /// annotated with their line in `file` when the template asks for it, in debug builds.
+    fn parse(
+        source: &str,
+        base: &Path,
+        file: Option<&Path>,
+    ) -> Result<(html::Content, Option<Annotation>), Error> {
+        let parse = |source: &str, file: Option<&Path>, annotation: Option<Annotation>| {
+            let mut parser = html::Parser::from_str(source).map_err(Error::Syntax)?;
+            for directive in ["scoped", "translate"] {
+                if !parser.directives(directive).is_empty() {
+                    return Err(Error::UnsupportedDirective(directive.to_string()));
+                }
+            }
+            if let (Some(file), Some(_), true) = (file, annotation, cfg!(debug_assertions)) {
+                parser.annotate(&display_path(file), 1);
+            }
+            parser.build().map_err(Error::Syntax)
+        };
+        let parser = html::Parser::from_str(source).map_err(Error::Syntax)?;
+        let annotation =
+            Annotation::from_directives(&parser.directives("annotate")).map_err(Error::Syntax)?;
+        let content =
+            parse(source, file, annotation)?.resolve(base, file, &mut |path: &Path| {
+                parse(&std::fs::read_to_string(path)?, Some(path), annotation)
+            })?;
+        Ok((content, annotation))
+    }
+
+    fn base(path: &Path) -> &Path {
+        path.parent().unwrap_or_else(|| Path::new(""))
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let (content, annotation) = Self::parse(
+            &std::fs::read_to_string(path)?,
+            Self::base(path),
+            Some(path),
+        )?;
+        Ok(Self {
+            path: Some(path.to_path_buf()),
+            modified: modified(path),
+            content,
+            annotation,
+        })
+    }
+
+    /// Parses a template from memory. Without a file there is nothing to annotate, so an
+    /// `annotate` directive is ignored.
+    pub fn from_source(source: &str) -> Result<Self, Error> {
+        let (content, _) = Self::parse(source, Path::new(""), None)?;
+        Ok(Self {
+            path: None,
+            modified: None,
+            content,
+            annotation: None,
+        })
+    }
+
+    /// Re-reads the file when it changed since the last load. Returns whether it did.
+    ///
+    /// Only the template itself is watched, not the layouts it extends.
+    pub fn reload_if_modified(&mut self) -> Result<bool, Error> {
+        let path = match &self.path {
+            Some(path) => path,
+            None => return Ok(false),
+        };
+        let current = modified(path);
+        if current.is_some() && current == self.modified {
+            return Ok(false);
+        }
+        let (content, annotation) = Self::parse(
+            &std::fs::read_to_string(path)?,
+            Self::base(path),
+            Some(path),
+        )?;
+        self.content = content;
+        self.annotation = annotation;
+        self.modified = current;
+        Ok(true)
+    }
+
+    pub fn render(&self, context: &Context) -> Result<HtmlContent, Error> {
+        let comments = self.annotation == Some(Annotation::Comment);
+        eval::html(&self.content, context, comments)
+    }
+}
+
+/// A css stylesheet parsed at runtime.
+#[derive(Debug)]
+pub struct Stylesheet {
+    content: css::Content,
+}
+
+impl Stylesheet {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::from_source(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn from_source(source: &str) -> Result<Self, Error> {
+        let content = css::Parser::from_str(source)
+            .and_then(css::Parser::build)
+            .map_err(Error::Syntax)?;
+        Ok(Self {
+            content: content.flatten(),
+        })
+    }
+
+    pub fn render(&self, context: &Context) -> Result<CssContent, Error> {
+        eval::css(&self.content, context)
+    }
+}
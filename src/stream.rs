@@ -0,0 +1,161 @@
+use std::collections::VecDeque;
+use std::future::Future;
+
+use bytes::{Bytes, BytesMut};
+use futures::future::BoxFuture;
+use futures::stream::{self, Stream};
+
+use crate::html::write_open_tag;
+use crate::HtmlContent;
+
+/// An html tree whose parts may still be waiting for their data.
+///
+/// Rendered with `into_stream`, everything before a deferred part is sent before the part is
+/// awaited, so a page can flush its head while the body is being fetched.
+pub enum Content {
+    Element {
+        name: String,
+        class_names: Vec<String>,
+        properties: Vec<(String, String)>,
+        contents: Vec<Content>,
+    },
+    Ready(HtmlContent),
+    Deferred(BoxFuture<'static, HtmlContent>),
+    Fragment(Vec<Content>),
+}
+
+impl Content {
+    pub fn new_element(
+        name: String,
+        class_names: Vec<String>,
+        properties: Vec<(String, String)>,
+        contents: Vec<Content>,
+    ) -> Self {
+        Self::Element {
+            name,
+            class_names,
+            properties,
+            contents,
+        }
+    }
+
+    pub fn new_deferred<F>(future: F) -> Self
+    where
+        F: Future<Output = HtmlContent> + Send + 'static,
+    {
+        Self::Deferred(Box::pin(future))
+    }
+
+    pub fn new_fragment(contents: Vec<Content>) -> Self {
+        Self::Fragment(contents)
+    }
+
+    /// Renders the tree as a stream of `chunk_size` byte chunks.
+    ///
+    /// A shorter chunk is sent before awaiting a deferred part and at the end of the document.
+    pub fn into_stream(self, chunk_size: usize) -> impl Stream<Item = Bytes> + Send {
+        assert!(chunk_size > 0, "chunk size must not be zero");
+        let mut segments = VecDeque::new();
+        self.flatten(&mut segments);
+        let state = State {
+            segments,
+            buffer: BytesMut::new(),
+            chunk_size,
+        };
+        stream::unfold(state, |mut state| async move {
+            let chunk = state.next_chunk().await?;
+            Some((chunk, state))
+        })
+    }
+
+    fn flatten(self, segments: &mut VecDeque<Segment>) {
+        match self {
+            Content::Element {
+                name,
+                class_names,
+                properties,
+                contents,
+            } => {
+                let mut open = String::new();
+                write_open_tag(&mut open, &name, &class_names, &properties).unwrap();
+                Segment::push_text(segments, open);
+                for content in contents {
+                    content.flatten(segments);
+                }
+                Segment::push_text(segments, format!("</{}>", name));
+            }
+            Content::Ready(content) => segments.push_back(Segment::Ready(content)),
+            Content::Deferred(future) => segments.push_back(Segment::Deferred(future)),
+            Content::Fragment(contents) => {
+                for content in contents {
+                    content.flatten(segments);
+                }
+            }
+        }
+    }
+}
+
+/// Streams a template, like the output of `html!` or `html_file!`. It's rendered once the stream
+/// reaches it rather than up front.
+impl From<HtmlContent> for Content {
+    fn from(content: HtmlContent) -> Self {
+        Self::Ready(content)
+    }
+}
+
+impl HtmlContent {
+    /// Renders the content as a stream of `chunk_size` byte chunks.
+    pub fn into_stream(self, chunk_size: usize) -> impl Stream<Item = Bytes> + Send {
+        Content::from(self).into_stream(chunk_size)
+    }
+}
+
+enum Segment {
+    Text(String),
+    /// Rendered once the stream reaches it.
+    Ready(HtmlContent),
+    Deferred(BoxFuture<'static, HtmlContent>),
+}
+
+impl Segment {
+    fn push_text(segments: &mut VecDeque<Segment>, text: String) {
+        if let Some(Segment::Text(last)) = segments.back_mut() {
+            last.push_str(&text);
+        } else {
+            segments.push_back(Segment::Text(text));
+        }
+    }
+}
+
+struct State {
+    segments: VecDeque<Segment>,
+    buffer: BytesMut,
+    chunk_size: usize,
+}
+
+impl State {
+    async fn next_chunk(&mut self) -> Option<Bytes> {
+        loop {
+            if self.buffer.len() >= self.chunk_size {
+                return Some(self.buffer.split_to(self.chunk_size).freeze());
+            }
+            match self.segments.pop_front() {
+                Some(Segment::Text(text)) => self.buffer.extend_from_slice(text.as_bytes()),
+                Some(Segment::Ready(content)) => self
+                    .buffer
+                    .extend_from_slice(content.to_string().as_bytes()),
+                Some(Segment::Deferred(future)) => {
+                    if !self.buffer.is_empty() {
+                        // Flush what is ready before waiting on the data.
+                        self.segments.push_front(Segment::Deferred(future));
+                        return Some(self.buffer.split().freeze());
+                    }
+                    let text = future.await.to_string();
+                    self.buffer.extend_from_slice(text.as_bytes());
+                }
+                None if self.buffer.is_empty() => return None,
+                None => return Some(self.buffer.split().freeze()),
+            }
+        }
+    }
+}
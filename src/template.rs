@@ -0,0 +1,22 @@
+use std::fmt;
+
+use crate::HtmlContent;
+
+/// A struct rendered by a template file, usually through `#[derive(tent::Template)]`.
+pub trait Template {
+    fn render(&self) -> HtmlContent;
+
+    /// Approximate length of the rendered output, counting the static parts of the template.
+    fn size_hint(&self) -> usize;
+
+    fn render_into(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        write!(out, "{}", self.render())
+    }
+
+    fn render_string(&self) -> String {
+        let mut res = String::with_capacity(self.size_hint());
+        // Writing to a `String` does not fail
+        let _ = self.render_into(&mut res);
+        res
+    }
+}
@@ -0,0 +1,153 @@
+use std::fmt;
+
+/// An xml tree, rendered with strict escaping.
+#[derive(Debug)]
+pub enum Content {
+    Element {
+        name: String,
+        properties: Vec<(String, String)>,
+        contents: Vec<Content>,
+    },
+    /// A processing instruction, like the `<?xml version="1.0"?>` declaration.
+    Instruction {
+        target: String,
+        properties: Vec<(String, String)>,
+    },
+    Text(String),
+    CData(String),
+    Fragment(Vec<Content>),
+}
+
+/// Writes `text` with the characters markup would take escaped, and quotes too in attributes.
+fn write_escaped(f: &mut fmt::Formatter<'_>, text: &str, in_attribute: bool) -> fmt::Result {
+    let mut rest = text;
+    while let Some(index) = rest.find(['&', '<', '>', '"', '\'']) {
+        let escaped = match &rest[index..index + 1] {
+            "&" => "&amp;",
+            "<" => "&lt;",
+            ">" => "&gt;",
+            "\"" if in_attribute => "&quot;",
+            "'" if in_attribute => "&apos;",
+            other => other,
+        };
+        f.write_str(&rest[..index])?;
+        f.write_str(escaped)?;
+        rest = &rest[index + 1..];
+    }
+    f.write_str(rest)
+}
+
+fn write_properties(f: &mut fmt::Formatter<'_>, properties: &[(String, String)]) -> fmt::Result {
+    for (name, value) in properties {
+        write!(f, " {}=\"", name)?;
+        write_escaped(f, value, true)?;
+        write!(f, "\"")?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for Content {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Content::Element {
+                name,
+                properties,
+                contents,
+            } => {
+                write!(f, "<{}", name)?;
+                write_properties(f, properties)?;
+                if contents.is_empty() {
+                    return write!(f, "/>");
+                }
+                write!(f, ">")?;
+                for content in contents.iter() {
+                    content.fmt(f)?;
+                }
+                write!(f, "</{}>", name)
+            }
+            Content::Instruction { target, properties } => {
+                write!(f, "<?{}", target)?;
+                write_properties(f, properties)?;
+                write!(f, "?>")
+            }
+            Content::Text(text) => write_escaped(f, text, false),
+            Content::CData(text) => {
+                // `]]>` would end the section, so it is split across two of them
+                write!(f, "<![CDATA[{}]]>", text.replace("]]>", "]]]]><![CDATA[>"))
+            }
+            Content::Fragment(contents) => {
+                for content in contents.iter() {
+                    content.fmt(f)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Content {
+    pub fn new_element(
+        name: String,
+        properties: Vec<(String, String)>,
+        contents: Vec<Content>,
+    ) -> Self {
+        Self::Element {
+            name,
+            properties,
+            contents,
+        }
+    }
+
+    pub fn new_text(text: String) -> Self {
+        Self::Text(text)
+    }
+
+    pub fn new_fragment(contents: Vec<Content>) -> Self {
+        Self::Fragment(contents)
+    }
+}
+
+/// Values a `{}` group of `xml!` can hold. Text is escaped, while xml content is nested as is.
+pub trait IntoXml {
+    fn into_xml(self) -> Content;
+}
+
+impl IntoXml for Content {
+    fn into_xml(self) -> Content {
+        self
+    }
+}
+
+impl<T: IntoXml> IntoXml for Vec<T> {
+    fn into_xml(self) -> Content {
+        Content::Fragment(self.into_iter().map(IntoXml::into_xml).collect())
+    }
+}
+
+impl<T: IntoXml + Clone> IntoXml for &T {
+    fn into_xml(self) -> Content {
+        self.clone().into_xml()
+    }
+}
+
+impl<T: IntoXml> IntoXml for Option<T> {
+    fn into_xml(self) -> Content {
+        self.map_or_else(|| Content::Fragment(vec![]), IntoXml::into_xml)
+    }
+}
+
+macro_rules! impl_into_xml_text {
+    ($($ty:ty),*) => {
+        $(
+            impl IntoXml for $ty {
+                fn into_xml(self) -> Content {
+                    Content::Text(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_into_xml_text!(
+    String, &str, char, bool, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64
+);
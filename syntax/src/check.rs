@@ -0,0 +1,313 @@
+//! Cross-checks the classes and ids of templates against the selectors of stylesheets.
+//!
+//! Meant for build scripts:
+//!
+//! ```no_run
+//! let root = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+//! let report = tent_syntax::check::scan(root.as_ref(), &["src"]).unwrap();
+//! report.warn();
+//! ```
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
+
+use crate::css::{self, selector};
+use crate::html;
+use crate::util::unquote;
+
+/// Classes and ids used by templates and defined by stylesheets.
+#[derive(Debug, Default)]
+pub struct Report {
+    used_classes: BTreeSet<String>,
+    used_ids: BTreeSet<String>,
+    selectors: Vec<String>,
+    errors: Vec<String>,
+}
+
+impl Report {
+    fn add_stylesheet(&mut self, source: &str) -> Result<(), String> {
+        let content = css::Parser::from_str(source)?.build()?;
+        self.add_items(content.flatten().items);
+        Ok(())
+    }
+
+    fn add_items(&mut self, items: Vec<css::Item>) {
+        for item in items {
+            match item {
+                // Conditional rules hold rules of their own but aren't selectors
+                css::Item::Node { name, children } if name.starts_with('@') => {
+                    self.add_items(children)
+                }
+                css::Item::Node { name, .. } => {
+                    if !self.selectors.contains(&name) {
+                        self.selectors.push(name);
+                    }
+                }
+                css::Item::Declaration(..) => (),
+            }
+        }
+    }
+
+    fn add_template(
+        &mut self,
+        source: &str,
+        base: &Path,
+        file: Option<&Path>,
+    ) -> Result<(), String> {
+        let parse = |source: &str| html::Parser::from_str(source)?.build();
+        let content = parse(source).and_then(|content| {
+            content.resolve(base, file, &mut |path: &Path| {
+                std::fs::read_to_string(path)
+                    .map_err(|e| e.to_string())
+                    .and_then(|source| parse(&source))
+            })
+        });
+        self.add_content(&content?);
+        Ok(())
+    }
+
+    fn add_content(&mut self, content: &html::Content) {
+        match content {
+            html::Content::Element {
+                class_names,
+                properties,
+                contents,
+                ..
+            } => {
+                let literal = |value: &String| (!value.starts_with('{')).then(|| unquote(value));
+                for class_name in class_names.iter().filter(|name| !name.starts_with('{')) {
+                    self.used_classes.insert(class_name.clone());
+                }
+                for (name, value) in properties.iter() {
+                    match (name.as_str(), literal(value)) {
+                        ("class", Some(value)) => self
+                            .used_classes
+                            .extend(value.split_whitespace().map(str::to_string)),
+                        ("id", Some(value)) => {
+                            self.used_ids.insert(value);
+                        }
+                        _ => (),
+                    }
+                }
+                for content in contents.iter() {
+                    self.add_content(content);
+                }
+            }
+            html::Content::Component { contents, .. } | html::Content::Block { contents, .. } => {
+                for content in contents.iter() {
+                    self.add_content(content);
+                }
+            }
+            html::Content::Extends { blocks, .. } => {
+                for block in blocks.iter() {
+                    self.add_content(block);
+                }
+            }
+            html::Content::Text(_) | html::Content::CData(_) => (),
+        }
+    }
+
+    /// Selectors needing a class or an id no template uses.
+    ///
+    /// Empty while some file couldn't be read or parsed, as the classes it uses are unknown.
+    pub fn unused_selectors(&self) -> Vec<&str> {
+        if !self.errors.is_empty() {
+            return vec![];
+        }
+        self.selectors
+            .iter()
+            .filter(|name| {
+                selector::names(name, '.')
+                    .iter()
+                    .any(|class| !self.used_classes.contains(*class))
+                    || selector::names(name, '#')
+                        .iter()
+                        .any(|id| !self.used_ids.contains(*id))
+            })
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Classes used by templates which no stylesheet defines.
+    pub fn undefined_classes(&self) -> Vec<&str> {
+        let defined = self
+            .selectors
+            .iter()
+            .flat_map(|name| selector::names(name, '.'))
+            .collect::<BTreeSet<_>>();
+        self.used_classes
+            .iter()
+            .map(String::as_str)
+            .filter(|class| !defined.contains(class))
+            .collect()
+    }
+
+    /// Files which couldn't be read or parsed, with the reason.
+    pub fn errors(&self) -> &[String] {
+        &self.errors
+    }
+
+    pub fn is_clean(&self) -> bool {
+        self.errors.is_empty()
+            && self.unused_selectors().is_empty()
+            && self.undefined_classes().is_empty()
+    }
+
+    /// Prints the findings as `cargo:warning` lines of a build script.
+    pub fn warn(&self) {
+        for line in self.to_string().lines() {
+            println!("cargo:warning={}", line);
+        }
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for error in self.errors.iter() {
+            writeln!(f, "{}", error)?;
+        }
+        for selector in self.unused_selectors() {
+            writeln!(f, "unused css rule `{}`", selector)?;
+        }
+        for class in self.undefined_classes() {
+            writeln!(f, "class `{}` is not defined by any stylesheet", class)?;
+        }
+        Ok(())
+    }
+}
+
+/// The first string literal in a token stream.
+fn first_literal(tokens: TokenStream) -> Option<String> {
+    tokens.into_iter().find_map(|token| match token {
+        TokenTree::Literal(literal) => {
+            let literal = literal.to_string();
+            (literal.starts_with('"') || literal.starts_with('r')).then(|| unquote(&literal))
+        }
+        _ => None,
+    })
+}
+
+/// The last string literal in a token stream, past the modes of `css!(scoped, ...)`.
+fn last_literal(tokens: TokenStream) -> Option<String> {
+    let literals = tokens
+        .into_iter()
+        .filter_map(|token| match token {
+            TokenTree::Literal(literal) => Some(literal.to_string()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    literals.last().map(|literal| unquote(literal))
+}
+
+struct Scanner<'a> {
+    root: &'a Path,
+    /// The rust file being scanned.
+    file: PathBuf,
+    report: Report,
+}
+
+impl Scanner<'_> {
+    /// Records why `path` couldn't be checked.
+    fn error(&mut self, path: &Path, result: Result<(), String>) {
+        if let Err(error) = result {
+            let path = path.strip_prefix(self.root).unwrap_or(path);
+            self.report
+                .errors
+                .push(format!("{}: {}", path.display(), error));
+        }
+    }
+
+    fn add_file(&mut self, path: &str) {
+        let path = self.root.join(path);
+        let base = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        let result = std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|source| self.report.add_template(&source, &base, Some(&path)));
+        self.error(&path, result);
+    }
+
+    /// Finds `html!`, `css!`, `html_file!` and `#[template(...)]` in rust tokens.
+    fn tokens(&mut self, tokens: TokenStream) {
+        let tokens = tokens.into_iter().collect::<Vec<_>>();
+        for (index, token) in tokens.iter().enumerate() {
+            match (token, tokens.get(index + 1), tokens.get(index + 2)) {
+                (
+                    TokenTree::Ident(name),
+                    Some(TokenTree::Punct(bang)),
+                    Some(TokenTree::Group(group)),
+                ) if bang.as_char() == '!' => match name.to_string().as_str() {
+                    "html" => {
+                        if let Some(source) = first_literal(group.stream()) {
+                            let result = self.report.add_template(&source, self.root, None);
+                            self.error(&self.file.clone(), result);
+                        }
+                    }
+                    "css" => {
+                        if let Some(source) = last_literal(group.stream()) {
+                            let result = self.report.add_stylesheet(&source);
+                            self.error(&self.file.clone(), result);
+                        }
+                    }
+                    "html_file" => {
+                        if let Some(path) = first_literal(group.stream()) {
+                            self.add_file(&path);
+                        }
+                    }
+                    _ => self.tokens(group.stream()),
+                },
+                (TokenTree::Ident(name), Some(TokenTree::Group(group)), _)
+                    if name == "template" && group.delimiter() == Delimiter::Parenthesis =>
+                {
+                    if let Some(path) = first_literal(group.stream()) {
+                        self.add_file(&path);
+                    }
+                }
+                (TokenTree::Group(group), _, _) => self.tokens(group.stream()),
+                _ => (),
+            }
+        }
+    }
+
+    fn dir(&mut self, dir: &Path) -> io::Result<()> {
+        let mut entries = std::fs::read_dir(dir)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<io::Result<Vec<PathBuf>>>()?;
+        entries.sort();
+        for path in entries {
+            if path.is_dir() {
+                self.dir(&path)?;
+            } else if path.extension().is_some_and(|extension| extension == "rs") {
+                let source = std::fs::read_to_string(&path)?;
+                match TokenStream::from_str(&source) {
+                    Ok(tokens) => {
+                        self.file = path;
+                        self.tokens(tokens);
+                    }
+                    Err(error) => self.error(&path, Err(error.to_string())),
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Scans the rust files under `dirs` of a crate for templates and stylesheets.
+///
+/// Template file paths are resolved against `root`, the manifest directory, like `html_file!`
+/// does.
+pub fn scan(root: &Path, dirs: &[&str]) -> io::Result<Report> {
+    let mut scanner = Scanner {
+        root,
+        file: PathBuf::new(),
+        report: Report::default(),
+    };
+    for dir in dirs {
+        scanner.dir(&root.join(dir))?;
+    }
+    Ok(scanner.report)
+}
@@ -0,0 +1,730 @@
+//! Turns html documents and plain stylesheets into templates of `html!` and `css!`.
+
+use crate::format::INDENT;
+use crate::html::attribute_name;
+use crate::svg;
+use crate::util::camelcase_to_dashed;
+
+/// Elements without contents or end tag.
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+/// Elements whose contents are text up to their end tag.
+const RAW_TEXT_ELEMENTS: &[&str] = &["script", "style", "textarea", "title"];
+
+/// Elements whose whitespace is kept as written.
+const PREFORMATTED_ELEMENTS: &[&str] = &["pre", "textarea"];
+
+/// Elements whose start tag ends an open `p`.
+const CLOSING_P: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "div",
+    "dl",
+    "fieldset",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hr",
+    "main",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "table",
+    "ul",
+];
+
+fn is_ident(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// `font-size` as `fontSize`, the way names are written in templates.
+fn dashed_to_camelcase(name: &str) -> String {
+    let mut res = String::with_capacity(name.len());
+    let mut upper = false;
+    for character in name.chars() {
+        if character == '-' {
+            upper = true;
+        } else if upper {
+            res.push(character.to_ascii_uppercase());
+            upper = false;
+        } else {
+            res.push(character);
+        }
+    }
+    res
+}
+
+#[derive(Debug)]
+enum Node {
+    Element {
+        name: String,
+        attributes: Vec<(String, Option<String>)>,
+        children: Vec<Node>,
+    },
+    Text(String),
+    Comment(String),
+}
+
+/// Whether the start tag of `name` ends the open element `open`.
+fn closes(open: &str, name: &str) -> bool {
+    match open {
+        "p" => CLOSING_P.contains(&name),
+        "li" => name == "li",
+        "dt" | "dd" => matches!(name, "dt" | "dd"),
+        "tr" => name == "tr",
+        "td" | "th" => matches!(name, "td" | "th" | "tr"),
+        "option" => matches!(name, "option" | "optgroup"),
+        _ => false,
+    }
+}
+
+/// Moves the innermost open element into its parent.
+fn close_last(stack: &mut Vec<Node>) {
+    if stack.len() < 2 {
+        return;
+    }
+    let node = stack.pop().unwrap();
+    if let Some(Node::Element { children, .. }) = stack.last_mut() {
+        children.push(node);
+    }
+}
+
+fn push_child(stack: &mut [Node], node: Node) {
+    if let Some(Node::Element { children, .. }) = stack.last_mut() {
+        children.push(node);
+    }
+}
+
+fn element_name(node: &Node) -> &str {
+    match node {
+        Node::Element { name, .. } => name,
+        _ => "",
+    }
+}
+
+/// A start tag, like `<a href="/">`.
+struct Tag {
+    /// Length of the tag in the source.
+    len: usize,
+    name: String,
+    attributes: Vec<(String, Option<String>)>,
+    self_closing: bool,
+}
+
+fn start_tag(source: &str) -> Result<Tag, String> {
+    let unclosed = || {
+        format!(
+            "unclosed tag `{}`",
+            source.lines().next().unwrap_or_default()
+        )
+    };
+    let is_end = |c: char| c.is_whitespace() || c == '/' || c == '>';
+    let name_len = source[1..].find(is_end).ok_or_else(unclosed)?;
+    let name = source[1..1 + name_len].to_string();
+    let mut attributes = vec![];
+    let mut rest = &source[1 + name_len..];
+    loop {
+        rest = rest.trim_start();
+        if let Some(tail) = rest.strip_prefix('>') {
+            return Ok(Tag {
+                len: source.len() - tail.len(),
+                name,
+                attributes,
+                self_closing: false,
+            });
+        }
+        if let Some(tail) = rest.strip_prefix("/>") {
+            return Ok(Tag {
+                len: source.len() - tail.len(),
+                name,
+                attributes,
+                self_closing: true,
+            });
+        }
+        if let Some(tail) = rest.strip_prefix('/') {
+            rest = tail;
+            continue;
+        }
+        if rest.is_empty() {
+            return Err(unclosed());
+        }
+        let len = rest
+            .find(|c: char| is_end(c) || c == '=')
+            .unwrap_or(rest.len())
+            .max(1);
+        let attribute = rest[..len].to_string();
+        rest = rest[len..].trim_start();
+        let tail = match rest.strip_prefix('=') {
+            Some(tail) => tail.trim_start(),
+            None => {
+                attributes.push((attribute, None));
+                continue;
+            }
+        };
+        let (value, len) = match tail.chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                let end = tail[1..].find(quote).ok_or_else(unclosed)?;
+                (&tail[1..1 + end], end + 2)
+            }
+            _ => {
+                let end = tail
+                    .find(|c: char| c.is_whitespace() || c == '>')
+                    .unwrap_or(tail.len());
+                (&tail[..end], end)
+            }
+        };
+        attributes.push((attribute, Some(value.to_string())));
+        rest = &tail[len..];
+    }
+}
+
+/// Parses an html document the way browsers mostly do, closing elements left open.
+fn parse(source: &str) -> Result<Vec<Node>, String> {
+    let mut stack = vec![Node::Element {
+        name: String::new(),
+        attributes: vec![],
+        children: vec![],
+    }];
+    let mut rest = source;
+    while !rest.is_empty() {
+        if let Some(tail) = rest.strip_prefix("<!--") {
+            let end = tail.find("-->").ok_or("unclosed comment")?;
+            push_child(&mut stack, Node::Comment(tail[..end].to_string()));
+            rest = &tail[end + 3..];
+        } else if rest.starts_with("<!") || rest.starts_with("<?") {
+            // Doctypes and processing instructions have no equivalent in templates
+            let end = rest.find('>').ok_or("unclosed declaration")?;
+            rest = &rest[end + 1..];
+        } else if let Some(tail) = rest.strip_prefix("</") {
+            let end = tail.find('>').ok_or("unclosed end tag")?;
+            let name = tail[..end].trim();
+            if let Some(index) = stack
+                .iter()
+                .rposition(|node| element_name(node).eq_ignore_ascii_case(name))
+                .filter(|index| *index > 0)
+            {
+                while stack.len() > index {
+                    close_last(&mut stack);
+                }
+            }
+            rest = &tail[end + 1..];
+        } else if rest.starts_with('<') && rest[1..].starts_with(|c: char| c.is_ascii_alphabetic())
+        {
+            let Tag {
+                len,
+                name,
+                attributes,
+                self_closing,
+            } = start_tag(rest)?;
+            rest = &rest[len..];
+            let lowercase = name.to_ascii_lowercase();
+            while stack.len() > 1
+                && closes(
+                    &element_name(&stack[stack.len() - 1]).to_ascii_lowercase(),
+                    &lowercase,
+                )
+            {
+                close_last(&mut stack);
+            }
+            let mut children = vec![];
+            if RAW_TEXT_ELEMENTS.contains(&lowercase.as_str()) && !self_closing {
+                let end = rest
+                    .to_ascii_lowercase()
+                    .find(&format!("</{}", lowercase))
+                    .unwrap_or(rest.len());
+                if end > 0 {
+                    children.push(Node::Text(rest[..end].to_string()));
+                }
+                rest = &rest[end..];
+                rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+            }
+            let element = Node::Element {
+                name,
+                attributes,
+                children,
+            };
+            let is_open = !self_closing
+                && !VOID_ELEMENTS.contains(&lowercase.as_str())
+                && !RAW_TEXT_ELEMENTS.contains(&lowercase.as_str());
+            if is_open {
+                stack.push(element);
+            } else {
+                push_child(&mut stack, element);
+            }
+        } else {
+            let end = rest[1..].find('<').map_or(rest.len(), |end| end + 1);
+            push_child(&mut stack, Node::Text(rest[..end].to_string()));
+            rest = &rest[end..];
+        }
+    }
+    while stack.len() > 1 {
+        close_last(&mut stack);
+    }
+    match stack.pop() {
+        Some(Node::Element { children, .. }) => Ok(children),
+        _ => Ok(vec![]),
+    }
+}
+
+/// Contents as templates write them, with whitespace collapsed and the indentation of the
+/// document dropped.
+fn contents(children: &[Node], preformatted: bool) -> Vec<&Node> {
+    children
+        .iter()
+        .filter(|node| match node {
+            Node::Text(text) => preformatted || !text.trim().is_empty() || !text.contains('\n'),
+            _ => true,
+        })
+        .collect()
+}
+
+fn text(text: &str, first: bool, last: bool) -> String {
+    let mut text = text;
+    let leading = &text[..text.len() - text.trim_start().len()];
+    if first && leading.contains('\n') {
+        text = text.trim_start();
+    }
+    let trailing = &text[text.trim_end().len()..];
+    if last && trailing.contains('\n') {
+        text = text.trim_end();
+    }
+    let mut res = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.starts_with(char::is_whitespace) {
+        res.insert(0, ' ');
+    }
+    if text.ends_with(char::is_whitespace) && res != " " {
+        res.push(' ');
+    }
+    res
+}
+
+/// An attribute name as written in templates, in camelCase when it converts back.
+fn attribute(name: &str, in_svg: bool) -> String {
+    for candidate in [dashed_to_camelcase(name), name.to_string()] {
+        if is_ident(&candidate) && attribute_name(&candidate, in_svg) == name {
+            return candidate;
+        }
+    }
+    match name.split_once(':') {
+        Some((prefix, local)) if is_ident(prefix) && is_ident(local) => name.to_string(),
+        _ => format!("{:?}", name),
+    }
+}
+
+struct Writer {
+    lines: Vec<String>,
+}
+
+impl Writer {
+    fn push(&mut self, depth: usize, line: String) {
+        self.lines.push(format!("{}{}", INDENT.repeat(depth), line));
+    }
+
+    fn comment(&mut self, depth: usize, comment: &str) {
+        for line in comment.trim().lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                self.push(depth, "//".to_string());
+            } else {
+                self.push(depth, format!("// {}", line));
+            }
+        }
+    }
+
+    /// Indents a raw `script` or `style` body below its element.
+    fn raw(&mut self, depth: usize, body: &str) {
+        let lines = body
+            .lines()
+            .skip_while(|line| line.trim().is_empty())
+            .collect::<Vec<_>>();
+        let end = lines
+            .iter()
+            .rposition(|line| !line.trim().is_empty())
+            .map_or(0, |index| index + 1);
+        let lines = &lines[..end];
+        let base = lines
+            .iter()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.len() - line.trim_start().len())
+            .min()
+            .unwrap_or_default();
+        for line in lines {
+            if line.trim().is_empty() {
+                self.lines.push(String::new());
+            } else {
+                self.push(depth, line[base..].trim_end().to_string());
+            }
+        }
+    }
+
+    fn nodes(
+        &mut self,
+        depth: usize,
+        nodes: &[&Node],
+        in_svg: bool,
+        preformatted: bool,
+    ) -> Result<(), String> {
+        for (index, node) in nodes.iter().enumerate() {
+            match node {
+                Node::Text(value) if preformatted => self.push(depth, format!("{:?}", value)),
+                Node::Text(value) => {
+                    let value = text(value, index == 0, index + 1 == nodes.len());
+                    if !value.is_empty() {
+                        self.push(depth, format!("{:?}", value));
+                    }
+                }
+                Node::Comment(comment) => self.comment(depth, comment),
+                Node::Element { .. } => self.element(depth, node, in_svg, preformatted)?,
+            }
+        }
+        Ok(())
+    }
+
+    fn element(
+        &mut self,
+        depth: usize,
+        node: &Node,
+        in_svg: bool,
+        preformatted: bool,
+    ) -> Result<(), String> {
+        let (name, attributes, children) = match node {
+            Node::Element {
+                name,
+                attributes,
+                children,
+            } => (name, attributes, children),
+            _ => return Ok(()),
+        };
+        let in_svg = in_svg || name.eq_ignore_ascii_case("svg");
+        let name = match svg::ELEMENTS
+            .iter()
+            .find(|element| in_svg && element.eq_ignore_ascii_case(name))
+        {
+            Some(element) => element.to_string(),
+            None if in_svg => name.to_string(),
+            None => name.to_ascii_lowercase(),
+        };
+        // Custom elements, like `my-widget`, keep their dashes
+        let is_name = match name.split_once(':') {
+            Some((prefix, local)) => is_ident(prefix) && is_ident(local),
+            None => name.split('-').all(is_ident),
+        };
+        if !is_name {
+            return Err(format!("`<{}>` has no equivalent in templates", name));
+        }
+
+        let mut line = name.clone();
+        let mut properties = vec![];
+        for (attribute, value) in attributes.iter() {
+            let attribute = if in_svg {
+                attribute.to_string()
+            } else {
+                attribute.to_ascii_lowercase()
+            };
+            let value = value.as_deref().unwrap_or_default();
+            if attribute == "class" {
+                for class_name in value.split_whitespace() {
+                    if is_ident(class_name) {
+                        line.push_str(&format!(".{}", class_name));
+                    } else {
+                        line.push_str(&format!(".{{{:?}}}", class_name));
+                    }
+                }
+            } else if attribute == "id" {
+                properties.insert(0, format!("id={:?}", value));
+            } else {
+                let attribute = self::attribute(&attribute, in_svg);
+                properties.push(format!("{}={:?}", attribute, value));
+            }
+        }
+        for property in properties {
+            line.push(' ');
+            line.push_str(&property);
+        }
+
+        let lowercase = name.to_ascii_lowercase();
+        if lowercase == "script" || lowercase == "style" {
+            self.push(depth, line);
+            if let Some(Node::Text(body)) = children.first() {
+                self.raw(depth + 1, body);
+            }
+            return Ok(());
+        }
+        let preformatted = preformatted || PREFORMATTED_ELEMENTS.contains(&lowercase.as_str());
+        let nodes = contents(children, preformatted);
+        if let [Node::Text(value)] = nodes.as_slice() {
+            let value = if preformatted {
+                value.to_string()
+            } else {
+                text(value, true, true)
+            };
+            if !value.is_empty() {
+                line.push_str(&format!(" {:?}", value));
+            }
+            self.push(depth, line);
+            return Ok(());
+        }
+        self.push(depth, line);
+        // The contents of `foreignObject` are html again
+        let in_svg = in_svg && name != "foreignObject";
+        self.nodes(depth + 1, &nodes, in_svg, preformatted)
+    }
+}
+
+/// Converts an html document or fragment into an `html!` template.
+///
+/// Class names become `.class`, and attribute names are written in camelCase when the template
+/// turns them back into the same name. Attributes without a value are given an empty one, and
+/// comments become `//` lines. Text and attribute values are kept as written, since templates
+/// don't escape them either.
+pub fn html(source: &str) -> Result<String, String> {
+    let nodes = parse(source)?;
+    let mut writer = Writer { lines: vec![] };
+    writer.nodes(0, &contents(&nodes, false), false, false)?;
+    Ok(writer.lines.join("\n"))
+}
+
+/// Drops the `/* */` comments of a stylesheet.
+fn strip_comments(source: &str) -> String {
+    let mut res = String::with_capacity(source.len());
+    let mut rest = source;
+    while let Some(start) = rest.find("/*") {
+        res.push_str(&rest[..start]);
+        rest = rest[start + 2..]
+            .find("*/")
+            .map_or("", |end| &rest[start + 2 + end + 2..]);
+    }
+    res.push_str(rest);
+    res
+}
+
+/// Index of the first of `targets` in `source` outside of quotes and brackets, so the `}`
+/// closing a block is found past the blocks it holds.
+fn find_outside(source: &str, targets: &[char]) -> Option<usize> {
+    let mut depth = 0;
+    let mut quote = None;
+    for (index, c) in source.char_indices() {
+        match (quote, c) {
+            (Some(open), c) if c == open => quote = None,
+            (Some(_), _) => (),
+            (None, '"' | '\'') => quote = Some(c),
+            (None, c) if depth == 0 && targets.contains(&c) => return Some(index),
+            (None, '(' | '[' | '{') => depth += 1,
+            (None, ')' | ']' | '}') => depth -= 1,
+            _ => (),
+        }
+    }
+    None
+}
+
+/// Splits `source` at each of `separator` outside of quotes and parentheses.
+fn split_outside(source: &str, separator: char) -> Vec<&str> {
+    let mut res = vec![];
+    let mut rest = source;
+    while let Some(index) = find_outside(rest, &[separator]) {
+        res.push(&rest[..index]);
+        rest = &rest[index + 1..];
+    }
+    res.push(rest);
+    res
+}
+
+/// A rule of the converted stylesheet, nesting the rules its selector prefixes.
+struct Rule {
+    selector: String,
+    /// At-rules are never merged with one another.
+    mergeable: bool,
+    declarations: Vec<String>,
+    children: Vec<Rule>,
+}
+
+impl Rule {
+    fn new(selector: String, mergeable: bool) -> Self {
+        Self {
+            selector,
+            mergeable,
+            declarations: vec![],
+            children: vec![],
+        }
+    }
+}
+
+/// Adds a rule below the last rule sharing its first selector, so the order of rules is kept.
+fn insert(rules: &mut Vec<Rule>, path: &[String], declarations: Vec<String>) {
+    let (first, rest) = match path.split_first() {
+        Some(split) => split,
+        None => return,
+    };
+    if !rules
+        .last()
+        .is_some_and(|last| last.mergeable && last.selector == *first)
+    {
+        rules.push(Rule::new(first.clone(), true));
+    }
+    let last = rules.last_mut().unwrap();
+    if rest.is_empty() {
+        last.declarations.extend(declarations);
+    } else {
+        insert(&mut last.children, rest, declarations);
+    }
+}
+
+/// The parts of a selector which nest, like `.nav`, `a` for `.nav a`. Combinators stay with
+/// the part they precede, like `> li`.
+fn selector_path(selector: &str) -> Vec<String> {
+    let selector = selector.split_whitespace().collect::<Vec<_>>().join(" ");
+    if find_outside(&selector, &[',']).is_some() {
+        return vec![format!("{:?}", selector)];
+    }
+    let mut parts: Vec<String> = vec![];
+    let mut combinator = None;
+    let mut rest = selector.as_str();
+    while !rest.is_empty() {
+        if let Some(tail) = rest.strip_prefix(['>', '+', '~']) {
+            combinator = Some(&rest[..1]);
+            rest = tail.trim_start();
+            continue;
+        }
+        let end = find_outside(rest, &[' ', '>', '+', '~']).unwrap_or(rest.len());
+        let part = &rest[..end];
+        rest = rest[end..].trim_start();
+        let part = match combinator.take() {
+            Some(combinator) => format!("{} {}", combinator, part),
+            None => part.to_string(),
+        };
+        let is_plain = match part.strip_prefix(['.', '#']) {
+            Some(name) => is_ident(name),
+            None => is_ident(&part),
+        };
+        parts.push(if is_plain {
+            part
+        } else {
+            format!("{:?}", part)
+        });
+    }
+    parts
+}
+
+fn declarations(body: &str) -> Result<Vec<String>, String> {
+    let mut res = vec![];
+    for declaration in split_outside(body, ';') {
+        if declaration.trim().is_empty() {
+            continue;
+        }
+        let (name, value) = declaration
+            .split_once(':')
+            .ok_or_else(|| format!("invalid declaration `{}`", declaration.trim()))?;
+        let name = name.trim();
+        let name = if name.starts_with("--") {
+            name.to_string()
+        } else {
+            name.to_ascii_lowercase()
+        };
+        let camelcase = dashed_to_camelcase(&name);
+        let name = if is_ident(&camelcase) && camelcase_to_dashed(&camelcase) == name {
+            camelcase
+        } else {
+            format!("{:?}", name)
+        };
+        res.push(format!("{}: {:?}", name, value.trim()));
+    }
+    Ok(res)
+}
+
+/// At-rules holding other rules which templates can nest, like `@media`.
+const CONDITIONAL_RULES: &[&str] = &["media", "supports", "container", "layer"];
+
+/// Converts a plain stylesheet into a `css!` template.
+///
+/// Rules following one another are nested under the selectors they start with, like `a` below
+/// `.nav` for `.nav a`. Parts of selectors templates can't write as is are quoted, and so are
+/// property names which don't convert back from camelCase, like custom properties. Conditional
+/// at-rules, like `@media`, keep their condition quoted and hold their rules indented beneath
+/// them. Other at-rules holding rules, like `@keyframes`, have no equivalent and give an error.
+pub fn css(source: &str) -> Result<String, String> {
+    let source = strip_comments(source);
+    let rules = parse_rules(&source)?;
+    let mut lines = vec![];
+    for (index, rule) in rules.iter().enumerate() {
+        if index > 0 {
+            lines.push(String::new());
+        }
+        write_rule(rule, 0, &mut lines);
+    }
+    Ok(lines.join("\n"))
+}
+
+/// The rules of a stylesheet, or of the block of a conditional at-rule.
+fn parse_rules(source: &str) -> Result<Vec<Rule>, String> {
+    let mut rules = vec![];
+    let mut rest = source.trim_start();
+    while !rest.is_empty() {
+        let open = find_outside(rest, &['{', ';']).ok_or("unclosed rule")?;
+        let prelude = rest[..open].trim();
+        if rest[open..].starts_with(';') {
+            return Err(format!("`{}` has no equivalent in templates", prelude));
+        }
+        let close = find_outside(&rest[open + 1..], &['}']).ok_or("unclosed rule")?;
+        let body = &rest[open + 1..open + 1 + close];
+        rest = rest[open + 1 + close + 1..].trim_start();
+        if find_outside(body, &['{']).is_some() {
+            let (name, condition) = prelude
+                .strip_prefix('@')
+                .map(|at_rule| {
+                    at_rule
+                        .split_once(char::is_whitespace)
+                        .unwrap_or((at_rule, ""))
+                })
+                .filter(|(name, _)| CONDITIONAL_RULES.contains(name))
+                .ok_or_else(|| format!("`{}` has no equivalent in templates", prelude))?;
+            let condition = condition.split_whitespace().collect::<Vec<_>>().join(" ");
+            let selector = if condition.is_empty() {
+                format!("@{}", name)
+            } else {
+                format!("@{} {:?}", name, condition)
+            };
+            let mut rule = Rule::new(selector, false);
+            rule.children = parse_rules(body)?;
+            rules.push(rule);
+            continue;
+        }
+        let declarations = declarations(body)?;
+
+        if let Some(at_rule) = prelude.strip_prefix('@') {
+            let name = dashed_to_camelcase(at_rule);
+            if !is_ident(&name) || camelcase_to_dashed(&name) != at_rule {
+                return Err(format!("`{}` has no equivalent in templates", prelude));
+            }
+            let mut rule = Rule::new(format!("@{}", name), false);
+            rule.declarations = declarations;
+            rules.push(rule);
+        } else {
+            insert(&mut rules, &selector_path(prelude), declarations);
+        }
+    }
+    Ok(rules)
+}
+
+fn write_rule(rule: &Rule, depth: usize, lines: &mut Vec<String>) {
+    lines.push(format!("{}{}", INDENT.repeat(depth), rule.selector));
+    for declaration in rule.declarations.iter() {
+        lines.push(format!("{}{}", INDENT.repeat(depth + 1), declaration));
+    }
+    for child in rule.children.iter() {
+        write_rule(child, depth + 1, lines);
+    }
+}
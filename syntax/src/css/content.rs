@@ -0,0 +1,88 @@
+/// Whether a rule named `name` holds other rules, like `@media (max-width: 600px)`.
+fn is_conditional(name: &str) -> bool {
+    ["@media", "@supports", "@container", "@layer"]
+        .iter()
+        .any(|prefix| name.starts_with(prefix))
+}
+
+#[derive(Debug)]
+pub enum Item {
+    Node { name: String, children: Vec<Item> },
+    Declaration(String, String),
+}
+
+impl Item {
+    fn flatten(self, namespace: Option<String>) -> (Option<Item>, Vec<Item>) {
+        match self {
+            // Conditional rules, like `@media`, keep the rules they hold in a block of their own
+            Item::Node { name, children } if is_conditional(&name) => {
+                let mut declarations = vec![];
+                let mut nodes = vec![];
+                for child in children {
+                    let (declaration, mut child_nodes) = child.flatten(namespace.clone());
+                    declarations.extend(declaration);
+                    nodes.append(&mut child_nodes);
+                }
+                if let (false, Some(namespace)) = (declarations.is_empty(), namespace) {
+                    nodes.insert(
+                        0,
+                        Item::Node {
+                            name: namespace,
+                            children: declarations,
+                        },
+                    );
+                }
+                let rule = Item::Node {
+                    name,
+                    children: nodes,
+                };
+                (None, vec![rule])
+            }
+            Item::Node { name, children } => {
+                let my_name = if let Some(namespace) = namespace {
+                    format!("{} {}", namespace, name)
+                } else {
+                    name
+                };
+
+                let mut declarations = vec![];
+                let mut nodes = vec![];
+                for child in children {
+                    let (declaration, mut child_nodes) = child.flatten(Some(my_name.clone()));
+                    if let Some(declaration) = declaration {
+                        declarations.push(declaration);
+                    }
+                    nodes.append(&mut child_nodes);
+                }
+                nodes.push(Item::Node {
+                    name: my_name,
+                    children: declarations,
+                });
+                // Conditional rules override the rule they are nested in
+                nodes.sort_by_key(
+                    |node| matches!(node, Item::Node { name, .. } if is_conditional(name)),
+                );
+                (None, nodes)
+            }
+            declaration => (Some(declaration), vec![]),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Content {
+    pub items: Vec<Item>,
+}
+
+impl Content {
+    pub fn flatten(self) -> Self {
+        let mut res = vec![];
+        for item in self.items {
+            let (declaration, mut nodes) = item.flatten(None);
+            // `Parser::build` rejects declarations outside of rules
+            assert!(declaration.is_none());
+            res.append(&mut nodes);
+        }
+        Self { items: res }
+    }
+}
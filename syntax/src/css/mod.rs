@@ -0,0 +1,6 @@
+mod content;
+mod parser;
+pub mod selector;
+
+pub use content::{Content, Item};
+pub use parser::Parser;
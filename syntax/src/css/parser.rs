@@ -1,7 +1,6 @@
-use std::str::FromStr;
-
 use crate::css::{Content, Item};
-use proc_macro::{TokenStream, TokenTree};
+use crate::util::{tokenize, Indentation};
+use proc_macro2::TokenTree;
 
 #[derive(Debug)]
 pub enum Node {
@@ -11,6 +10,15 @@ pub enum Node {
     Group(String),
 }
 
+impl std::fmt::Display for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Node::Ident(text) | Node::Literal(text) | Node::Group(text) => write!(f, "{}", text),
+            Node::Punct(punct) => write!(f, "{}", punct),
+        }
+    }
+}
+
 #[derive(Default)]
 pub struct LineBuilder {
     level: Option<usize>,
@@ -29,6 +37,7 @@ impl LineBuilder {
     fn build(self) -> Option<Line> {
         Some(Line {
             level: self.level?,
+            number: 0,
             nodes: self.nodes,
         })
     }
@@ -47,19 +56,23 @@ impl LineBuilder {
 #[derive(Debug)]
 pub struct Line {
     level: usize,
+    /// Index of the line in the stylesheet source.
+    number: usize,
     nodes: Vec<Node>,
 }
 
 impl Line {
-    pub fn process(self) -> Option<BuilderNode> {
+    pub fn process(self) -> Result<BuilderNode, String> {
         #[derive(Debug)]
         enum State {
             StandBy,
             HasPrefix(char),
             HasIdent(String),
+            HasQuotedName(String),
             HasAccumulatedIdent(String),
             HasAccumulatedPunct(String),
             NeedDeclarationValue(String),
+            NeedQuotedDeclarationValue(String),
             Done,
         }
         let mut state = State::StandBy;
@@ -69,6 +82,20 @@ impl Line {
             match (&state, node) {
                 (State::StandBy, Node::Punct('@')) => state = State::HasPrefix('@'),
                 (State::StandBy, Node::Ident(ident)) => state = State::HasIdent(ident),
+                (State::StandBy, Node::Literal(ref literal)) => {
+                    // Quoted names are taken as written, like `"a:hover"` or `"--accent"`
+                    state = State::HasQuotedName(crate::util::unquote(literal))
+                }
+                (State::HasQuotedName(name), Node::Punct(':')) => {
+                    state = State::NeedQuotedDeclarationValue(name.to_string())
+                }
+                (
+                    State::NeedQuotedDeclarationValue(name),
+                    Node::Literal(ref value) | Node::Group(ref value),
+                ) => {
+                    res = Some(Item::Declaration(name.to_string(), value.to_string()));
+                    state = State::Done;
+                }
                 (State::StandBy, Node::Punct('.')) => {
                     state = State::HasAccumulatedPunct(".".to_string())
                 }
@@ -82,6 +109,14 @@ impl Line {
                         crate::util::camelcase_to_dashed(ident)
                     ))
                 }
+                (State::HasIdent(ident), Node::Literal(ref literal)) if ident.starts_with('@') => {
+                    // Condition of an at-rule, like `@media "(max-width: 600px)"`
+                    state = State::HasAccumulatedIdent(format!(
+                        "{} {}",
+                        ident,
+                        crate::util::unquote(literal)
+                    ))
+                }
                 (State::HasIdent(ident), Node::Punct('.')) => {
                     state = State::HasAccumulatedPunct(format!("{} .", ident))
                 }
@@ -96,6 +131,13 @@ impl Line {
                     ));
                     state = State::Done;
                 }
+                (State::NeedDeclarationValue(ref ident), Node::Group(ref group)) => {
+                    res = Some(Item::Declaration(
+                        crate::util::camelcase_to_dashed(ident),
+                        group.to_string(),
+                    ));
+                    state = State::Done;
+                }
                 (State::HasIdent(prev), Node::Ident(ref ident)) => {
                     state = State::HasAccumulatedIdent(format!("{} {}", prev, ident))
                 }
@@ -111,14 +153,14 @@ impl Line {
                 (State::HasAccumulatedPunct(prev), Node::Punct(ref ident)) => {
                     state = State::HasAccumulatedPunct(format!("{}{}", prev, ident))
                 }
-                (state, node) => {
-                    panic!("{:?}, {:?}", state, node);
+                (_, node) => {
+                    return Err(format!("line {}: unexpected `{}`", self.number + 1, node));
                 }
             }
         }
 
         match state {
-            State::HasIdent(ident) => {
+            State::HasIdent(ident) | State::HasQuotedName(ident) => {
                 res = Some(Item::Node {
                     name: ident,
                     children: vec![],
@@ -131,18 +173,15 @@ impl Line {
                 });
             }
             State::Done => (),
-            state => {
-                panic!("{:?}", state);
-            }
+            _ => return Err(format!("line {}: unfinished line", self.number + 1)),
         }
 
-        if let Some(res) = res {
-            Some(BuilderNode {
+        match res {
+            Some(res) => Ok(BuilderNode {
                 level: self.level,
                 inner: res,
-            })
-        } else {
-            None
+            }),
+            None => Err(format!("line {}: unfinished line", self.number + 1)),
         }
     }
 }
@@ -159,6 +198,10 @@ pub struct BuilderNode {
 }
 
 impl BuilderNode {
+    fn is_node(&self) -> bool {
+        matches!(self.inner, Item::Node { .. })
+    }
+
     pub fn set_children(&mut self, children_new: Vec<Item>) {
         match &mut self.inner {
             Item::Node {
@@ -172,21 +215,30 @@ impl BuilderNode {
 }
 
 impl Parser {
-    pub fn from_str(input: &str) -> Option<Self> {
-        let lines = input
-            .lines()
-            .map(|line| {
-                let mut tokens = TokenStream::from_str(line).unwrap().into_iter();
-                let mut line_builder =
-                    LineBuilder::new(line.find(|c: char| !c.is_whitespace()).unwrap_or_default());
-                while let Some(token) = tokens.next() {
-                    line_builder.put(token);
-                }
-                line_builder.build().unwrap()
-            })
-            .collect();
+    /// Parses a stylesheet. Lines are indented relative to the least indented one, with either
+    /// tabs or spaces.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(input: &str) -> Result<Self, String> {
+        let mut indentation = Indentation::new(input);
+        let mut lines = vec![];
+        let mut input_lines = input.lines().enumerate();
+        while let Some((number, line)) = input_lines.next() {
+            let (tokens, _) = tokenize(number, line, &mut input_lines)?;
+            if tokens.is_empty() {
+                continue;
+            }
+            let level = indentation.level(number, line)?;
+            indentation.nest(number, level)?;
+            let mut line_builder = LineBuilder::new(level);
+            for token in tokens {
+                line_builder.put(token);
+            }
+            let mut line = line_builder.build().unwrap();
+            line.number = number;
+            lines.push(line);
+        }
 
-        Some(Self { lines })
+        Ok(Self { lines })
     }
 
     fn clean_stack(stack: &mut Vec<BuilderNode>) -> Vec<Item> {
@@ -199,7 +251,7 @@ impl Parser {
         leaves
     }
 
-    pub fn build(self) -> Content {
+    pub fn build(self) -> Result<Content, String> {
         #[derive(Debug)]
         enum State {
             BackIndent,
@@ -215,7 +267,8 @@ impl Parser {
             if line.nodes.is_empty() {
                 continue;
             }
-            let node = line.process().unwrap();
+            let number = line.number;
+            let node = line.process()?;
 
             loop {
                 let state = if let Some(last) = stack.last() {
@@ -229,6 +282,12 @@ impl Parser {
                     State::Empty
                 };
                 match state {
+                    State::Indent if matches!(stack.last(), Some(last) if !last.is_node()) => {
+                        return Err(format!(
+                            "line {}: a declaration can't have indented contents",
+                            number + 1
+                        ));
+                    }
                     State::BackIndent => {
                         let mut siblings = Self::clean_stack(&mut stack);
                         if stack.is_empty() {
@@ -238,6 +297,14 @@ impl Parser {
                             parent.set_children(siblings);
                         }
                     }
+                    State::Empty | State::Indent | State::Sibling
+                        if !node.is_node() && stack.iter().all(|last| last.level >= node.level) =>
+                    {
+                        return Err(format!(
+                            "line {}: a declaration needs a selector",
+                            number + 1
+                        ));
+                    }
                     State::Empty | State::Indent | State::Sibling => {
                         stack.push(node);
                         break;
@@ -254,6 +321,6 @@ impl Parser {
                 parent.set_children(siblings);
             }
         }
-        Content { items: res }
+        Ok(Content { items: res })
     }
 }
@@ -0,0 +1,13 @@
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '-' || c == '_'
+}
+
+/// Names following `prefix` in a selector, like the classes of `.notice .head` for `.`.
+pub fn names(selector: &str, prefix: char) -> Vec<&str> {
+    selector
+        .split(prefix)
+        .skip(1)
+        .map(|part| &part[..part.find(|c| !is_name_char(c)).unwrap_or(part.len())])
+        .filter(|name| !name.is_empty())
+        .collect()
+}
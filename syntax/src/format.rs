@@ -0,0 +1,356 @@
+//! Rewrites templates and stylesheets with consistent indentation and spacing.
+
+use crate::util::{is_word_char, literal_len, tokenize, tokens, Indentation};
+
+/// Indentation of each nesting level.
+pub(crate) const INDENT: &str = "    ";
+
+/// Lines configuring a template, kept as they are.
+const DIRECTIVES: &[&str] = &["annotate", "lint", "scoped", "translate"];
+
+fn is_value(token: &str) -> bool {
+    token.starts_with(['"', '{']) || token.starts_with("r\"") || token.starts_with("r#")
+}
+
+fn is_word(token: &str) -> bool {
+    token.starts_with(is_word_char)
+}
+
+/// An element, component or processing instruction line, written as
+/// `tag.class attributes contents` with `id` as the first attribute.
+fn element_line(tokens: &[&str]) -> Option<String> {
+    let mut head = String::new();
+    let mut rest = tokens;
+    match rest {
+        ["@" | "?", name, tail @ ..] if is_word(name) => {
+            head = format!("{}{}", rest[0], name);
+            rest = tail;
+            // Paths of components
+            while let [":", ":", name, tail @ ..] = rest {
+                head.push_str(&format!("::{}", name));
+                rest = tail;
+            }
+        }
+        [name, ":", local, tail @ ..] if is_word(name) && is_word(local) => {
+            head = format!("{}:{}", name, local);
+            rest = tail;
+        }
+        [name, tail @ ..] if is_word(name) => {
+            head = name.to_string();
+            rest = tail;
+        }
+        [".", ..] => (),
+        _ => return None,
+    }
+
+    let mut classes = String::new();
+    let mut id = None;
+    let mut attributes = vec![];
+    let mut contents = vec![];
+    loop {
+        match rest {
+            [] => break,
+            [".", class, tail @ ..] if is_word(class) || class.starts_with('{') => {
+                classes.push_str(&format!(".{}", class));
+                rest = tail;
+            }
+            [prefix, ":", name, "=", value, tail @ ..] if is_word(prefix) && is_word(name) => {
+                attributes.push(format!("{}:{}={}", prefix, name, value));
+                rest = tail;
+            }
+            [name, "=", value, tail @ ..] if is_word(name) || is_value(name) => {
+                if *name == "id" {
+                    id = Some(format!("id={}", value));
+                } else {
+                    attributes.push(format!("{}={}", name, value));
+                }
+                rest = tail;
+            }
+            [value, tail @ ..] if is_value(value) => {
+                contents.push(value.to_string());
+                rest = tail;
+            }
+            _ => return None,
+        }
+    }
+    let mut res = format!("{}{}", head, classes);
+    for part in id.into_iter().chain(attributes).chain(contents) {
+        res.push(' ');
+        res.push_str(&part);
+    }
+    Some(res)
+}
+
+/// A template line without its indentation, in canonical form when it is understood.
+fn html_line(line: &str) -> String {
+    let trimmed = line.trim();
+    let tokens = match tokens(trimmed) {
+        Some(tokens) if !tokens.is_empty() => tokens,
+        _ => return trimmed.to_string(),
+    };
+    let canonical = match tokens.as_slice() {
+        [first, ..] if DIRECTIVES.contains(first) => None,
+        ["block" | "extends", ..] => Some(tokens.join(" ")),
+        ["!", keyword, rest @ ..] => Some(
+            std::iter::once(format!("!{}", keyword))
+                .chain(rest.iter().map(|token| token.to_string()))
+                .collect::<Vec<_>>()
+                .join(" "),
+        ),
+        tokens if tokens.iter().all(|token| is_value(token)) => Some(tokens.join(" ")),
+        tokens => element_line(tokens),
+    };
+    canonical.unwrap_or_else(|| trimmed.to_string())
+}
+
+fn indent_of(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+/// Nesting depth of a line, or `None` when the parsers would reject its indentation.
+fn depth(indentation: &mut Indentation, number: usize, line: &str) -> Option<usize> {
+    let level = indentation.level(number, line).ok()?;
+    indentation.nest(number, level).ok()
+}
+
+/// A line whose expression spans the lines after it, which move along with it and keep their
+/// indentation past its own.
+fn push_continued(res: &mut Vec<String>, depth: usize, line: &str, joined: &[&str]) {
+    let indent = INDENT.repeat(depth);
+    push_line(res, format!("{}{}", indent, line.trim()));
+    let base = indent_of(line);
+    for next in joined {
+        let own = indent_of(next);
+        if next.trim().is_empty() {
+            res.push(String::new());
+        } else {
+            res.push(format!(
+                "{}{}{}",
+                indent,
+                &next[base.min(own)..own],
+                next.trim()
+            ));
+        }
+    }
+}
+
+/// Drops blank lines at the ends and runs of blank lines in between.
+fn push_line(res: &mut Vec<String>, line: String) {
+    if line.trim().is_empty() && res.last().is_none_or(|last| last.is_empty()) {
+        return;
+    }
+    res.push(line);
+}
+
+fn finish(mut lines: Vec<String>) -> String {
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    lines.join("\n")
+}
+
+/// Formats an html template, indenting each level by four spaces.
+///
+/// Element lines are written as `tag.class id=... attributes contents`. The raw bodies of
+/// `script` and `style` keep their own indentation relative to each other. Templates the parser
+/// rejects for their indentation are given back unchanged.
+pub fn html(source: &str) -> String {
+    try_html(source).unwrap_or_else(|| source.to_string())
+}
+
+fn try_html(source: &str) -> Option<String> {
+    let mut res = vec![];
+    let mut indentation = Indentation::new(source);
+    let mut lines = source.lines().enumerate().peekable();
+    while let Some((number, line)) = lines.next() {
+        if line.trim().is_empty() {
+            push_line(&mut res, String::new());
+            continue;
+        }
+        let depth = depth(&mut indentation, number, line)?;
+        let level = indentation.relative(line);
+        let (_, joined) = tokenize(number, line, &mut lines).ok()?;
+        if !joined.is_empty() {
+            push_continued(&mut res, depth, line, &joined);
+            continue;
+        }
+        let formatted = html_line(line);
+        let is_raw = tokens(&formatted).is_some_and(|tokens| has_no_contents(&tokens));
+        push_line(&mut res, format!("{}{}", INDENT.repeat(depth), formatted));
+
+        if is_raw {
+            let mut body = vec![];
+            while let Some((_, next)) = lines.peek() {
+                if !next.trim().is_empty() && indentation.relative(next) <= level {
+                    break;
+                }
+                body.push(*next);
+                lines.next();
+            }
+            let base = body
+                .iter()
+                .filter(|line| !line.trim().is_empty())
+                .map(|line| indent_of(line))
+                .min()
+                .unwrap_or_default();
+            for line in body {
+                if line.trim().is_empty() {
+                    res.push(String::new());
+                } else {
+                    let indent = INDENT.repeat(depth + 1);
+                    res.push(format!("{}{}", indent, &line[base..].trim_end()));
+                }
+            }
+        }
+    }
+    Some(finish(res))
+}
+
+/// Whether an element line of `script` or `style` has no inline contents.
+fn has_no_contents(tokens: &[&str]) -> bool {
+    if !matches!(tokens.first(), Some(&"script") | Some(&"style")) {
+        return false;
+    }
+    let mut after_assign = false;
+    for token in tokens.iter() {
+        if is_value(token) && !after_assign {
+            return false;
+        }
+        after_assign = *token == "=";
+    }
+    true
+}
+
+/// Formats a stylesheet, indenting each level by four spaces and writing declarations as
+/// `name: value`, unless the parser rejects its indentation.
+pub fn css(source: &str) -> String {
+    try_css(source).unwrap_or_else(|| source.to_string())
+}
+
+fn try_css(source: &str) -> Option<String> {
+    let mut res = vec![];
+    let mut indentation = Indentation::new(source);
+    let mut lines = source.lines().enumerate();
+    while let Some((number, line)) = lines.next() {
+        if line.trim().is_empty() {
+            push_line(&mut res, String::new());
+            continue;
+        }
+        let depth = depth(&mut indentation, number, line)?;
+        let (_, joined) = tokenize(number, line, &mut lines).ok()?;
+        if !joined.is_empty() {
+            push_continued(&mut res, depth, line, &joined);
+            continue;
+        }
+        let formatted = match tokens(line.trim()) {
+            Some(tokens)
+                if tokens.len() > 2
+                    && tokens[1] == ":"
+                    && (is_word(tokens[0]) || is_value(tokens[0])) =>
+            {
+                format!("{}: {}", tokens[0], tokens[2..].join(" "))
+            }
+            // Selectors as written, like `"a:hover"`
+            Some(tokens) if tokens.len() == 1 && is_value(tokens[0]) => tokens[0].to_string(),
+            _ => line.split_whitespace().collect::<Vec<_>>().join(" "),
+        };
+        push_line(&mut res, format!("{}{}", INDENT.repeat(depth), formatted));
+    }
+    Some(finish(res))
+}
+
+/// Formats the raw string literals of `html!` and `css!` calls in Rust source, keeping the
+/// indentation of their first line.
+pub fn rust(source: &str) -> String {
+    let mut res = String::new();
+    let mut rest = source;
+    while let Some((start, is_css)) = next_call(rest) {
+        res.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let hashes = rest[1..].len() - rest[1..].trim_start_matches('#').len();
+        let len = match literal_len(rest) {
+            Some(len) => len,
+            None => break,
+        };
+        let body = &rest[2 + hashes..len - 1 - hashes];
+        res.push_str(&rest[..2 + hashes]);
+        res.push_str(&format_literal(body, is_css));
+        res.push_str(&rest[len - 1 - hashes..len]);
+        rest = &rest[len..];
+    }
+    res.push_str(rest);
+    res
+}
+
+/// Start of the next raw string passed to `html!` or `css!`, and whether it is a stylesheet.
+fn next_call(source: &str) -> Option<(usize, bool)> {
+    let mut offset = 0;
+    loop {
+        let rest = &source[offset..];
+        let (index, is_css, name) = ["html!", "css!"]
+            .iter()
+            .filter_map(|name| rest.find(name).map(|index| (index, *name == "css!", name)))
+            .min()?;
+        offset += index + name.len();
+        if source[..offset - name.len()].ends_with(is_word_char) {
+            continue;
+        }
+        let mut args = match source[offset..].trim_start().strip_prefix(['(', '{', '[']) {
+            Some(inner) => inner.trim_start(),
+            None => continue,
+        };
+        // Modes of `css!`, like `scoped,`
+        while is_css && !args.starts_with('r') {
+            let word = args.find(|c| !is_word_char(c)).unwrap_or(0);
+            match args[word..].trim_start().strip_prefix(',') {
+                Some(tail) if word > 0 => args = tail.trim_start(),
+                _ => break,
+            }
+        }
+        if args.starts_with("r\"") || args.starts_with("r#") {
+            return Some((source.len() - args.len(), is_css));
+        }
+    }
+}
+
+fn format_literal(body: &str, is_css: bool) -> String {
+    let lines = body.split('\n').collect::<Vec<_>>();
+    let template = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .copied()
+        .collect::<Vec<_>>();
+    // Templates starting on the line of the literal stay as they are.
+    if lines.len() < 3 || template.is_empty() || !lines[0].trim().is_empty() {
+        return body.to_string();
+    }
+    let base = " ".repeat(
+        template
+            .iter()
+            .map(|line| indent_of(line))
+            .min()
+            .unwrap_or(0),
+    );
+    let inner = lines[1..lines.len() - 1].join("\n");
+    let formatted = if is_css {
+        try_css(&inner)
+    } else {
+        try_html(&inner)
+    };
+    // Literals the parser rejects are kept byte for byte
+    let formatted = match formatted {
+        Some(formatted) => formatted,
+        None => return body.to_string(),
+    };
+    let mut res = vec![lines[0].to_string()];
+    res.extend(formatted.lines().map(|line| {
+        if line.is_empty() {
+            String::new()
+        } else {
+            format!("{}{}", base, line)
+        }
+    }));
+    res.push(lines[lines.len() - 1].to_string());
+    res.join("\n")
+}
@@ -0,0 +1,39 @@
+/// Environment variable annotating every template, like an `annotate` line.
+pub const ANNOTATE: &str = "TENT_ANNOTATE";
+
+/// How elements show the template line they come from, in debug builds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Annotation {
+    /// A `data-tent-src="file:line"` attribute.
+    Attribute,
+    /// A `<!-- tent: file:line -->` comment before the element.
+    Comment,
+}
+
+impl Annotation {
+    /// Applies `TENT_ANNOTATE`, then the `annotate` lines of the template: `annotate` or
+    /// `annotate attribute` adds attributes, `annotate comment` comments and `annotate none`
+    /// turns them off again.
+    pub fn from_directives(directives: &[&str]) -> Result<Option<Self>, String> {
+        let mut res = None;
+        let env = std::env::var(ANNOTATE).ok();
+        for directive in env
+            .iter()
+            .map(String::as_str)
+            .chain(directives.iter().copied())
+        {
+            res = match directive.trim() {
+                "" | "attribute" => Some(Self::Attribute),
+                "comment" => Some(Self::Comment),
+                "none" => None,
+                other => {
+                    return Err(format!(
+                        "unknown annotation `{}`, expected `attribute`, `comment` or `none`",
+                        other
+                    ))
+                }
+            };
+        }
+        Ok(res)
+    }
+}
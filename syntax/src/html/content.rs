@@ -0,0 +1,28 @@
+#[derive(Debug, Clone)]
+pub enum Content {
+    Element {
+        name: String,
+        class_names: Vec<String>,
+        properties: Vec<(String, String)>,
+        contents: Vec<Content>,
+    },
+    Text(String),
+    /// A CDATA section, written `!cdata "text"`. Only `xml!` renders these.
+    CData(String),
+    /// A named placeholder a page can fill. Holds the default contents.
+    Block {
+        name: String,
+        contents: Vec<Content>,
+    },
+    /// A call of a `tent::Component`, with `contents` passed as its children.
+    Component {
+        name: String,
+        properties: Vec<(String, String)>,
+        contents: Vec<Content>,
+    },
+    /// A page filling the blocks of the layout at the given path.
+    Extends {
+        layout: String,
+        blocks: Vec<Content>,
+    },
+}
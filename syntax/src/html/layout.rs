@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::html::Content;
+
+impl Content {
+    /// Replaces `extends` with the layout it names, filling the layout's blocks.
+    ///
+    /// Layout paths are relative to the directory of the template naming them, starting with
+    /// `base`. Unfilled blocks keep their default contents. Only the root of a template can be
+    /// an `extends`. `file` is the template itself, when it was read from one, so a layout
+    /// extending it is reported as a cycle.
+    pub fn resolve<E: From<String>>(
+        self,
+        base: &Path,
+        file: Option<&Path>,
+        load: &mut impl FnMut(&Path) -> Result<Content, E>,
+    ) -> Result<Content, E> {
+        let mut chain = file.map(Path::to_path_buf).into_iter().collect();
+        self.resolve_with(base, base, &mut chain, HashMap::new(), load)
+    }
+
+    fn has_extends(&self) -> bool {
+        match self {
+            Content::Extends { .. } => true,
+            Content::Element { contents, .. }
+            | Content::Component { contents, .. }
+            | Content::Block { contents, .. } => contents.iter().any(Content::has_extends),
+            Content::Text(_) | Content::CData(_) => false,
+        }
+    }
+
+    /// `chain` holds the files extended so far, shown relative to `root`.
+    fn resolve_with<E: From<String>>(
+        self,
+        root: &Path,
+        base: &Path,
+        chain: &mut Vec<PathBuf>,
+        mut overrides: HashMap<String, Vec<Content>>,
+        load: &mut impl FnMut(&Path) -> Result<Content, E>,
+    ) -> Result<Content, E> {
+        match self {
+            Content::Extends { layout, blocks } => {
+                for block in blocks {
+                    if let Content::Block { name, contents } = block {
+                        overrides.entry(name).or_insert(contents);
+                    }
+                }
+                let path = base.join(layout);
+                let canonical = |path: &Path| std::fs::canonicalize(path).ok();
+                let seen = chain.iter().any(|file| {
+                    *file == path
+                        || canonical(file).is_some_and(|file| Some(file) == canonical(&path))
+                });
+                chain.push(path.clone());
+                if seen {
+                    let files = chain
+                        .iter()
+                        .map(|file| {
+                            file.strip_prefix(root)
+                                .unwrap_or(file)
+                                .display()
+                                .to_string()
+                        })
+                        .collect::<Vec<_>>();
+                    return Err(format!("cyclic `extends`: {}", files.join(" -> ")).into());
+                }
+                let base = path.parent().map(Path::to_path_buf).unwrap_or_default();
+                load(&path)?.resolve_with(root, &base, chain, overrides, load)
+            }
+            content => {
+                let mut filled = content.fill(&mut overrides);
+                if filled.len() != 1 {
+                    return Err("a layout must have a single root element"
+                        .to_string()
+                        .into());
+                }
+                if filled[0].has_extends() {
+                    return Err("`extends` must be the root of a template"
+                        .to_string()
+                        .into());
+                }
+                Ok(filled.remove(0))
+            }
+        }
+    }
+
+    fn fill(self, overrides: &mut HashMap<String, Vec<Content>>) -> Vec<Content> {
+        match self {
+            Content::Element {
+                name,
+                class_names,
+                properties,
+                contents,
+            } => vec![Content::Element {
+                name,
+                class_names,
+                properties,
+                contents: contents
+                    .into_iter()
+                    .flat_map(|content| content.fill(overrides))
+                    .collect(),
+            }],
+            Content::Component {
+                name,
+                properties,
+                contents,
+            } => vec![Content::Component {
+                name,
+                properties,
+                contents: contents
+                    .into_iter()
+                    .flat_map(|content| content.fill(overrides))
+                    .collect(),
+            }],
+            Content::Block { name, contents } => {
+                // Taken out while filling, so a block can not contain itself.
+                let contents = match overrides.remove(&name) {
+                    Some(contents) => contents,
+                    None => contents,
+                };
+                contents
+                    .into_iter()
+                    .flat_map(|content| content.fill(overrides))
+                    .collect()
+            }
+            content => vec![content],
+        }
+    }
+}
@@ -0,0 +1,10 @@
+mod annotate;
+mod content;
+mod layout;
+mod names;
+mod parser;
+
+pub use annotate::{Annotation, ANNOTATE};
+pub use content::Content;
+pub(crate) use names::attribute_name;
+pub use parser::{Parser, SOURCE_ATTRIBUTE};
@@ -0,0 +1,66 @@
+use crate::html::Content;
+use crate::svg;
+use crate::util::{camelcase_to_dashed, unquote};
+
+/// The html or SVG name of a property written as `name` in a template.
+pub(crate) fn attribute_name(name: &str, in_svg: bool) -> String {
+    if name.starts_with('"') || name.starts_with('r') && name.contains('"') {
+        // Quoted names are taken as written
+        unquote(name)
+    } else if name.contains(':') {
+        // So are namespaced ones
+        name.to_string()
+    } else if in_svg && svg::CAMEL_CASE_ATTRIBUTES.contains(&name) || name == "viewBox" {
+        name.to_string()
+    } else {
+        camelcase_to_dashed(name)
+    }
+}
+
+fn element_name(name: &str, in_svg: bool) -> String {
+    if in_svg {
+        if let Some(element) = svg::ELEMENTS
+            .iter()
+            .find(|element| element.eq_ignore_ascii_case(name))
+        {
+            return element.to_string();
+        }
+    }
+    name.to_string()
+}
+
+impl Content {
+    /// Turns tag and property names written in the template into html or SVG names.
+    pub(crate) fn convert_names(&mut self, in_svg: bool) {
+        match self {
+            Content::Element {
+                name,
+                properties,
+                contents,
+                ..
+            } => {
+                let in_svg = in_svg || name.eq_ignore_ascii_case("svg");
+                *name = element_name(name, in_svg);
+                for (property, _) in properties.iter_mut() {
+                    *property = attribute_name(property, in_svg);
+                }
+                // The contents of `foreignObject` are html again
+                let in_svg = in_svg && name != "foreignObject";
+                for content in contents.iter_mut() {
+                    content.convert_names(in_svg);
+                }
+            }
+            Content::Component { contents, .. } | Content::Block { contents, .. } => {
+                for content in contents.iter_mut() {
+                    content.convert_names(in_svg);
+                }
+            }
+            Content::Extends { blocks, .. } => {
+                for block in blocks.iter_mut() {
+                    block.convert_names(in_svg);
+                }
+            }
+            Content::Text(_) | Content::CData(_) => (),
+        }
+    }
+}
@@ -0,0 +1,685 @@
+use crate::html::Content;
+use crate::util::{dashed_words, tokenize, Indentation};
+use proc_macro2::TokenTree;
+
+#[derive(Debug)]
+pub enum Node {
+    Ident(String),
+    Punct(char),
+    Literal(String),
+    Group(String),
+    /// A literal followed by `=`, naming a property as written.
+    QuotedName(String),
+}
+
+impl std::fmt::Display for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Node::Ident(text) | Node::Literal(text) | Node::Group(text) => write!(f, "{}", text),
+            Node::Punct(punct) => write!(f, "{}", punct),
+            Node::QuotedName(name) => write!(f, "{}=", name),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct LineBuilder {
+    level: Option<usize>,
+    nodes: Vec<Node>,
+    /// Words joined by dashes in the source of the line, not yet put together.
+    dashed: std::collections::VecDeque<String>,
+}
+
+impl LineBuilder {
+    fn new(level: usize, source: &str) -> Self {
+        Self {
+            level: Some(level),
+            dashed: dashed_words(source).into_iter().map(String::from).collect(),
+            ..Default::default()
+        }
+    }
+
+    // TODO: Replace Option with Result
+    fn build(self) -> Option<Line> {
+        Some(Line {
+            level: self.level?,
+            number: 0,
+            nodes: self.nodes,
+        })
+    }
+
+    fn put(&mut self, token: TokenTree) {
+        let node = match token {
+            TokenTree::Ident(ident) => Node::Ident(ident.to_string()),
+            TokenTree::Punct(punct) => Node::Punct(punct.as_char()),
+            TokenTree::Literal(literal) => Node::Literal(literal.to_string()),
+            TokenTree::Group(group) => Node::Group(group.to_string()),
+        };
+        if let (Node::Ident(ident), [.., Node::Ident(prefix), Node::Punct('-')]) =
+            (&node, self.nodes.as_slice())
+        {
+            // Dashed name written without spaces, like `http-equiv`
+            let name = format!("{}-{}", prefix, ident);
+            let is_whole = self.dashed.front() == Some(&name);
+            let is_part = self
+                .dashed
+                .front()
+                .is_some_and(|word| word.starts_with(&format!("{}-", name)));
+            if is_whole {
+                self.dashed.pop_front();
+            }
+            if is_whole || is_part {
+                self.nodes.truncate(self.nodes.len() - 2);
+                self.nodes.push(Node::Ident(name));
+                return;
+            }
+        }
+        if let (Node::Punct('='), Some(Node::Literal(_))) = (&node, self.nodes.last()) {
+            if let Some(Node::Literal(literal)) = self.nodes.pop() {
+                self.nodes.push(Node::QuotedName(literal));
+                return;
+            }
+        }
+        self.nodes.push(node);
+    }
+}
+
+#[derive(Debug)]
+pub struct Line {
+    level: usize,
+    /// Index of the line in the template source.
+    number: usize,
+    nodes: Vec<Node>,
+}
+
+impl Line {
+    /// Whether this is a `script` or `style` tag without inline contents, whose indented body
+    /// is taken as raw text.
+    fn has_raw_body(&self) -> bool {
+        if !matches!(self.nodes.first(), Some(Node::Ident(tag)) if tag == "script" || tag == "style")
+        {
+            return false;
+        }
+        let mut after_assign = false;
+        for node in self.nodes.iter() {
+            match node {
+                Node::Literal(_) | Node::Group(_) if !after_assign => return false,
+                _ => after_assign = matches!(node, Node::Punct('=') | Node::QuotedName(_)),
+            }
+        }
+        true
+    }
+
+    /// Whether this line configures the template, like `lint a11y`.
+    fn is_directive(&self) -> bool {
+        matches!(self.nodes.first(), Some(Node::Ident(ident)) if DIRECTIVES.contains(&ident.as_str()))
+    }
+
+    /// A text line at `level` holding the lines of a raw body, dedented to their least
+    /// indentation.
+    fn raw(body: &[&str], number: usize, level: usize) -> Option<Self> {
+        let mut body = body.to_vec();
+        while body.last().is_some_and(|line| line.trim().is_empty()) {
+            body.pop();
+        }
+        let indent = body
+            .iter()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.len() - line.trim_start().len())
+            .min()?;
+        let text = body
+            .iter()
+            .map(|line| line.get(indent..).unwrap_or_default())
+            .collect::<Vec<_>>()
+            .join("\n");
+        Some(Self {
+            level,
+            number,
+            nodes: vec![Node::Literal(format!("{:?}", text))],
+        })
+    }
+
+    /// Builds the node of this line. In xml, `block` and `extends` are ordinary elements.
+    pub fn process(self, xml: bool) -> Result<BuilderNode, String> {
+        #[derive(Debug)]
+        enum State {
+            StandBy,
+            HasIdent,
+            HasPropertyName(String),
+            NeedLocalName(String),
+            NeedPropertyValue(String),
+            NeedClassName,
+            NeedBlockName,
+            NeedLayoutPath,
+            NeedComponentName,
+            HasComponentName,
+            NeedLocalTagName,
+            NeedInstructionTarget,
+            NeedCDataKeyword,
+            NeedCData,
+            Done(BuilderNode),
+        }
+        let mut state = State::StandBy;
+        let mut tag = None;
+        let mut contents = vec![];
+        let mut class_names = vec![];
+        let mut properties: Vec<(String, String)> = vec![];
+        let mut component = false;
+
+        for node in self.nodes.into_iter() {
+            match (&state, node) {
+                (State::StandBy, Node::Ident(ident)) if ident == "block" && !xml => {
+                    state = State::NeedBlockName;
+                }
+                (State::StandBy, Node::Ident(ident)) if ident == "extends" && !xml => {
+                    state = State::NeedLayoutPath;
+                }
+                (State::NeedBlockName, Node::Ident(name)) => {
+                    state = State::Done(BuilderNode::Block {
+                        level: self.level,
+                        name,
+                        children: vec![],
+                    });
+                }
+                (State::NeedLayoutPath, Node::Literal(ref literal)) => {
+                    state = State::Done(BuilderNode::Extends {
+                        level: self.level,
+                        layout: crate::util::unquote(literal),
+                        children: vec![],
+                    });
+                }
+                (State::StandBy, Node::Punct('@')) => {
+                    tag = Some(String::new());
+                    component = true;
+                    state = State::NeedComponentName;
+                }
+                (State::NeedComponentName, Node::Ident(ident)) => {
+                    tag.get_or_insert_with(String::new).push_str(&ident);
+                    state = State::HasComponentName;
+                }
+                (State::NeedComponentName | State::HasComponentName, Node::Punct(':')) => {
+                    // Path separator
+                    tag.get_or_insert_with(String::new).push(':');
+                    state = State::NeedComponentName;
+                }
+                (State::HasComponentName, node) => {
+                    state = State::HasIdent;
+                    match node {
+                        Node::Ident(ident) => state = State::HasPropertyName(ident),
+                        Node::Literal(literal) => contents.push(literal),
+                        Node::Group(group) => contents.push(group),
+                        node => {
+                            return Err(format!("line {}: unexpected `{}`", self.number + 1, node))
+                        }
+                    }
+                }
+                (State::StandBy, Node::Ident(ident)) => {
+                    tag = Some(ident);
+                    state = State::HasIdent;
+                }
+                (State::HasIdent, Node::Punct(':'))
+                    if class_names.is_empty() && properties.is_empty() && contents.is_empty() =>
+                {
+                    // Namespaced tag, like `soap:Envelope`
+                    state = State::NeedLocalTagName;
+                }
+                (State::NeedLocalTagName, Node::Ident(ref local)) => {
+                    tag.get_or_insert_with(String::new)
+                        .push_str(&format!(":{}", local));
+                    state = State::HasIdent;
+                }
+                (State::StandBy, Node::Punct('?')) => {
+                    // Processing instruction, like `?xml version="1.0"`
+                    state = State::NeedInstructionTarget;
+                }
+                (State::NeedInstructionTarget, Node::Ident(ident)) => {
+                    tag = Some(format!("?{}", ident));
+                    state = State::HasIdent;
+                }
+                (State::StandBy, Node::Punct('!')) => {
+                    state = State::NeedCDataKeyword;
+                }
+                (State::NeedCDataKeyword, Node::Ident(ident)) if ident == "cdata" => {
+                    state = State::NeedCData;
+                }
+                (State::NeedCData, Node::Literal(text) | Node::Group(text)) => {
+                    state = State::Done(BuilderNode::CData {
+                        level: self.level,
+                        text,
+                    });
+                }
+                (State::StandBy, Node::Literal(ref literal)) => {
+                    state = State::Done(BuilderNode::Text {
+                        level: self.level,
+                        text: literal.to_string(),
+                    });
+                }
+                (State::StandBy, Node::Group(ref group)) => {
+                    state = State::Done(BuilderNode::Text {
+                        level: self.level,
+                        text: group.to_string(),
+                    });
+                }
+                (State::StandBy, Node::Punct('.')) => {
+                    tag = Some(String::from("div"));
+                    state = State::NeedClassName;
+                }
+                (State::HasIdent, Node::Punct('.')) => {
+                    state = State::NeedClassName;
+                }
+                (State::HasIdent, Node::Ident(ident)) => {
+                    // Receive property name, converted once the tree is built
+                    state = State::HasPropertyName(ident);
+                }
+                (State::HasIdent, Node::QuotedName(name)) => {
+                    state = State::NeedPropertyValue(name);
+                }
+                (State::HasPropertyName(prefix), Node::Punct(':')) => {
+                    // Namespaced name, like `xlink:href`
+                    state = State::NeedLocalName(format!("{}:", prefix));
+                }
+                (State::NeedLocalName(prefix), Node::Ident(ref local)) => {
+                    state = State::HasPropertyName(format!("{}{}", prefix, local));
+                }
+                (State::HasPropertyName(name), Node::Punct('=')) => {
+                    state = State::NeedPropertyValue(name.to_string());
+                }
+                (State::NeedPropertyValue(name), Node::Literal(ref literal)) => {
+                    properties.push((name.to_string(), literal.to_string()));
+                    state = State::HasIdent;
+                }
+                (State::NeedPropertyValue(name), Node::Group(ref group)) => {
+                    properties.push((name.to_string(), group.to_string()));
+                    state = State::HasIdent;
+                }
+                (State::HasIdent, Node::Literal(literal)) => {
+                    // TODO: Implement more
+                    contents.push(literal);
+                }
+                (State::HasIdent, Node::Group(group)) => {
+                    // TODO: Implement
+                    contents.push(group);
+                }
+                (State::NeedClassName, Node::Ident(ident)) => {
+                    class_names.push(ident);
+                    state = State::HasIdent;
+                }
+                (State::NeedClassName, Node::Group(group)) => {
+                    // Class name given by an expression, like `.{styles.notice}`
+                    class_names.push(group);
+                    state = State::HasIdent;
+                }
+                (_, node) => {
+                    return Err(format!("line {}: unexpected `{}`", self.number + 1, node));
+                }
+            }
+        }
+        let tag = match (state, tag) {
+            (State::Done(res), _) => return Ok(res),
+            (State::StandBy | State::HasIdent | State::HasPropertyName(_), Some(tag)) => tag,
+            (State::HasComponentName, Some(tag)) => tag,
+            _ => return Err(format!("line {}: unfinished line", self.number + 1)),
+        };
+        if component {
+            Ok(BuilderNode::Component {
+                level: self.level,
+                name: tag,
+                properties,
+                children: contents.into_iter().map(Content::Text).collect(),
+            })
+        } else if contents.is_empty() {
+            Ok(BuilderNode::Tag {
+                level: self.level,
+                tag,
+                class_names,
+                properties,
+                children: vec![],
+            })
+        } else {
+            Ok(BuilderNode::InlineTag {
+                level: self.level,
+                tag,
+                class_names,
+                properties,
+                contents,
+            })
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Parser {
+    lines: Vec<Line>,
+    /// Whether this is an xml document, without directives or layouts.
+    xml: bool,
+    directives: Vec<(String, String)>,
+    /// File and line number of the first line, for annotating elements with their source.
+    origin: Option<(String, usize)>,
+}
+
+/// Lines configuring the template rather than adding to it, like `lint a11y`.
+const DIRECTIVES: &[&str] = &["annotate", "lint", "scoped", "translate"];
+
+/// Attribute holding the source location of an element, added by `Parser::annotate`.
+pub const SOURCE_ATTRIBUTE: &str = "data-tent-src";
+
+#[derive(Debug)]
+pub enum BuilderNode {
+    InlineTag {
+        level: usize,
+        tag: String,
+        class_names: Vec<String>,
+        properties: Vec<(String, String)>,
+        contents: Vec<String>, // TODO: Use enum
+    },
+    Tag {
+        level: usize,
+        tag: String,
+        class_names: Vec<String>,
+        properties: Vec<(String, String)>,
+        children: Vec<Content>,
+    },
+    Text {
+        level: usize,
+        text: String,
+    },
+    CData {
+        level: usize,
+        text: String,
+    },
+    Block {
+        level: usize,
+        name: String,
+        children: Vec<Content>,
+    },
+    Extends {
+        level: usize,
+        layout: String,
+        children: Vec<Content>,
+    },
+    Component {
+        level: usize,
+        name: String,
+        properties: Vec<(String, String)>,
+        children: Vec<Content>,
+    },
+}
+
+impl BuilderNode {
+    /// Whether indented lines can go into this node.
+    fn has_children(&self) -> bool {
+        !matches!(
+            self,
+            Self::InlineTag { .. } | Self::Text { .. } | Self::CData { .. }
+        )
+    }
+
+    fn level(&self) -> usize {
+        match self {
+            Self::InlineTag { level, .. } => *level,
+            Self::Tag { level, .. } => *level,
+            Self::Text { level, .. } => *level,
+            Self::CData { level, .. } => *level,
+            Self::Block { level, .. } => *level,
+            Self::Extends { level, .. } => *level,
+            Self::Component { level, .. } => *level,
+        }
+    }
+
+    pub fn into_element(self) -> Content {
+        match self {
+            Self::InlineTag {
+                tag,
+                class_names,
+                properties,
+                contents,
+                ..
+            } => Content::Element {
+                name: tag,
+                class_names,
+                properties,
+                contents: contents.into_iter().map(Content::Text).collect(),
+            },
+            Self::Tag {
+                tag,
+                class_names,
+                properties,
+                children,
+                ..
+            } => Content::Element {
+                name: tag,
+                class_names,
+                properties,
+                contents: children,
+            },
+            Self::Text { text, .. } => Content::Text(text),
+            Self::CData { text, .. } => Content::CData(text),
+            Self::Block { name, children, .. } => Content::Block {
+                name,
+                contents: children,
+            },
+            Self::Extends {
+                layout, children, ..
+            } => Content::Extends {
+                layout,
+                blocks: children,
+            },
+            Self::Component {
+                name,
+                properties,
+                children,
+                ..
+            } => Content::Component {
+                name,
+                properties,
+                contents: children,
+            },
+        }
+    }
+
+    fn annotate(&mut self, location: String) {
+        if let Self::Tag { properties, .. } | Self::InlineTag { properties, .. } = self {
+            properties.push((SOURCE_ATTRIBUTE.to_string(), format!("{:?}", location)));
+        }
+    }
+
+    pub fn set_children(&mut self, new_children: Vec<Content>) {
+        match self {
+            Self::Tag {
+                ref mut children, ..
+            }
+            | Self::Block {
+                ref mut children, ..
+            }
+            | Self::Extends {
+                ref mut children, ..
+            } => {
+                *children = new_children;
+            }
+            Self::Component {
+                ref mut children, ..
+            } => {
+                // Inline text comes before the indented children
+                children.extend(new_children);
+            }
+            _ => panic!("Unreachable"),
+        }
+    }
+}
+
+impl Parser {
+    /// Parses an html template. Lines are indented relative to the least indented one, with
+    /// either tabs or spaces.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(input: &str) -> Result<Self, String> {
+        Self::parse(input, false)
+    }
+
+    /// Parses an xml template, where `script` and `style` bodies are ordinary lines, and every
+    /// line is content: xml elements can be named like directives.
+    pub fn from_xml_str(input: &str) -> Result<Self, String> {
+        Self::parse(input, true)
+    }
+
+    fn parse(input: &str, xml: bool) -> Result<Self, String> {
+        let mut indentation = Indentation::new(input);
+        let mut lines = vec![];
+        let mut input_lines = input.lines().enumerate().peekable();
+        while let Some((number, source)) = input_lines.next() {
+            let (tokens, joined) = tokenize(number, source, &mut input_lines)?;
+            let level = if tokens.is_empty() {
+                0
+            } else {
+                indentation.level(number, source)?
+            };
+            let text = std::iter::once(source).chain(joined).collect::<Vec<_>>();
+            let mut line_builder = LineBuilder::new(level, &text.join("\n"));
+            for token in tokens {
+                line_builder.put(token);
+            }
+            let mut line = line_builder.build().unwrap();
+            line.number = number;
+            if !line.nodes.is_empty() && (xml || !line.is_directive()) {
+                indentation.nest(number, level)?;
+            }
+            let is_raw = !xml && line.has_raw_body();
+            lines.push(line);
+
+            if is_raw {
+                let mut body = vec![];
+                while let Some((_, next)) = input_lines.peek() {
+                    if !next.trim().is_empty() && indentation.relative(next) <= level {
+                        break;
+                    }
+                    body.push(*next);
+                    input_lines.next();
+                }
+                lines.extend(Line::raw(&body, number + 1, level + 1));
+            }
+        }
+
+        let (directive_lines, lines): (Vec<_>, Vec<_>) = lines
+            .into_iter()
+            .partition(|line| !xml && line.is_directive());
+        let directives = directive_lines
+            .into_iter()
+            .map(|line| {
+                let mut nodes = line.nodes.iter().map(Node::to_string);
+                let name = nodes.next().unwrap_or_default();
+                (name, nodes.collect::<String>())
+            })
+            .collect();
+
+        Ok(Self {
+            lines,
+            xml,
+            directives,
+            origin: None,
+        })
+    }
+
+    /// Adds the file and line of each element to it, as a `data-tent-src` attribute. `line` is
+    /// the line number of the first line of the template.
+    pub fn annotate(&mut self, file: &str, line: usize) {
+        self.origin = Some((file.to_string(), line));
+    }
+
+    /// Arguments of the directive lines with the given name, like `a11y(imgAlt)` for `lint`.
+    pub fn directives(&self, name: &str) -> Vec<&str> {
+        self.directives
+            .iter()
+            .filter(|(directive, _)| directive == name)
+            .map(|(_, arguments)| arguments.as_str())
+            .collect()
+    }
+
+    /// Moves the nodes on top of the stack into their parent, giving them back if there is none.
+    fn clean_stack(stack: &mut Vec<BuilderNode>) -> Option<Vec<Content>> {
+        let leaf_level = stack.last().unwrap().level();
+        let mut leaves = vec![];
+        while stack.last().is_some_and(|node| node.level() == leaf_level) {
+            leaves.push(stack.pop().unwrap().into_element());
+        }
+        leaves.reverse();
+
+        match stack.last_mut() {
+            Some(parent) => {
+                parent.set_children(leaves);
+                None
+            }
+            None => Some(leaves),
+        }
+    }
+
+    /// Builds the top-level nodes, with names as written.
+    fn build_nodes(self) -> Result<Vec<Content>, String> {
+        #[derive(Debug)]
+        enum State {
+            BackIndent,
+            Sibling,
+            Indent,
+            Empty,
+        }
+
+        let mut roots = vec![];
+        let mut stack: Vec<BuilderNode> = vec![];
+        for line in self.lines.into_iter() {
+            if line.nodes.is_empty() {
+                continue;
+            }
+            let number = line.number;
+            let mut node = line.process(self.xml)?;
+            if let Some((file, first)) = &self.origin {
+                node.annotate(format!("{}:{}", file, first + number));
+            }
+
+            loop {
+                let state = if let Some(last) = stack.last() {
+                    use std::cmp::Ordering;
+                    match node.level().cmp(&last.level()) {
+                        Ordering::Greater => State::Indent,
+                        Ordering::Less => State::BackIndent,
+                        Ordering::Equal => State::Sibling,
+                    }
+                } else {
+                    State::Empty
+                };
+                match state {
+                    State::BackIndent => {
+                        roots.extend(Self::clean_stack(&mut stack).unwrap_or_default());
+                    }
+                    State::Indent if !stack.last().is_some_and(BuilderNode::has_children) => {
+                        return Err(format!(
+                            "line {}: text can't have indented contents",
+                            number + 1
+                        ));
+                    }
+                    State::Empty | State::Indent | State::Sibling => {
+                        stack.push(node);
+                        break;
+                    }
+                }
+            }
+        }
+        while !stack.is_empty() {
+            roots.extend(Self::clean_stack(&mut stack).unwrap_or_default());
+        }
+        Ok(roots)
+    }
+
+    /// Builds an html template, which has a single root element.
+    pub fn build(self) -> Result<Content, String> {
+        let mut roots = self.build_nodes()?;
+        if roots.len() != 1 {
+            return Err("a template must have a single root element".to_string());
+        }
+        let mut root = roots.remove(0);
+        root.convert_names(false);
+        Ok(root)
+    }
+
+    /// Builds an xml document, keeping every name exactly as written.
+    pub fn build_xml(self) -> Result<Vec<Content>, String> {
+        self.build_nodes()
+    }
+}
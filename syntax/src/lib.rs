@@ -0,0 +1,7 @@
+pub mod check;
+pub mod convert;
+pub mod css;
+pub mod format;
+pub mod html;
+pub mod svg;
+pub mod util;
@@ -0,0 +1,328 @@
+use std::str::FromStr;
+
+use proc_macro2::TokenStream;
+
+pub fn camelcase_to_dashed(property_name: &str) -> String {
+    let mut res = String::with_capacity(property_name.len() * 2);
+    for character in property_name.chars() {
+        if character.is_uppercase() {
+            res.push('-');
+            res.push_str(&character.to_lowercase().to_string());
+        } else {
+            res.push(character);
+        }
+    }
+    res
+}
+
+/// Unescapes the source text of a rust string literal. Other literals are kept as written.
+pub fn unquote(source: &str) -> String {
+    if let Some(raw) = source.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        return raw[hashes + 1..raw.len() - hashes - 1].to_string();
+    }
+    let inner = match source.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+        Some(inner) => inner,
+        None => return source.to_string(),
+    };
+    let mut res = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(character) = chars.next() {
+        if character != '\\' {
+            res.push(character);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => res.push('\n'),
+            Some('r') => res.push('\r'),
+            Some('t') => res.push('\t'),
+            Some('0') => res.push('\0'),
+            Some('u') => {
+                let code = chars
+                    .by_ref()
+                    .skip(1)
+                    .take_while(|c| *c != '}')
+                    .collect::<String>();
+                if let Some(character) =
+                    u32::from_str_radix(&code, 16).ok().and_then(char::from_u32)
+                {
+                    res.push(character);
+                }
+            }
+            Some('\n') => {
+                while chars.as_str().starts_with(char::is_whitespace) {
+                    chars.next();
+                }
+            }
+            Some(other) => res.push(other),
+            None => (),
+        }
+    }
+    res
+}
+
+/// Reads the indentation of template lines, relative to the leading whitespace all of them
+/// share, and checks that lines nest consistently.
+#[derive(Debug)]
+pub struct Indentation<'a> {
+    base: &'a str,
+    /// Whether the template indents with tabs, once a line is indented.
+    tabs: Option<bool>,
+    levels: Vec<usize>,
+}
+
+impl<'a> Indentation<'a> {
+    pub fn new(input: &'a str) -> Self {
+        let mut base: Option<&str> = None;
+        for line in input.lines().filter(|line| !line.trim().is_empty()) {
+            let indent = &line[..line.len() - line.trim_start().len()];
+            base = Some(match base {
+                Some(base) => {
+                    let common = base
+                        .char_indices()
+                        .zip(indent.chars())
+                        .find(|((_, a), b)| a != b)
+                        .map_or(base.len().min(indent.len()), |((index, _), _)| index);
+                    &base[..common]
+                }
+                None => indent,
+            });
+        }
+        Self {
+            base: base.unwrap_or_default(),
+            tabs: None,
+            levels: vec![],
+        }
+    }
+
+    /// Columns of indentation past the shared whitespace, counting tabs like spaces.
+    pub fn relative(&self, line: &str) -> usize {
+        let indent = &line[..line.len() - line.trim_start().len()];
+        indent
+            .strip_prefix(self.base)
+            .unwrap_or(indent)
+            .chars()
+            .count()
+    }
+
+    /// Level of the line at index `number`, failing when the template indents with both tabs
+    /// and spaces.
+    pub fn level(&mut self, number: usize, line: &str) -> Result<usize, String> {
+        let indent = &line[..line.len() - line.trim_start().len()];
+        let indent = indent.strip_prefix(self.base).unwrap_or(indent);
+        if let Some(first) = indent.chars().next() {
+            let tabs = first == '\t';
+            if indent.chars().any(|c| (c == '\t') != tabs) || *self.tabs.get_or_insert(tabs) != tabs
+            {
+                return Err(format!(
+                    "line {}: indentation mixes tabs and spaces",
+                    number + 1
+                ));
+            }
+        }
+        Ok(indent.chars().count())
+    }
+
+    /// Nesting depth of a line of the given level, failing when it dedents to a level no line
+    /// it follows has.
+    pub fn nest(&mut self, number: usize, level: usize) -> Result<usize, String> {
+        let mut dedented = false;
+        while self.levels.last().is_some_and(|last| *last > level) {
+            self.levels.pop();
+            dedented = true;
+        }
+        match self.levels.last() {
+            Some(last) if *last == level => (),
+            Some(_) if dedented => {
+                return Err(format!(
+                    "line {}: dedent doesn't line up with any enclosing level",
+                    number + 1
+                ))
+            }
+            _ => self.levels.push(level),
+        }
+        Ok(self.levels.len() - 1)
+    }
+}
+
+/// Length of the character literal at the start of `source`, like `'{'` or `'\n'`.
+fn char_literal_len(source: &str) -> Option<usize> {
+    let escape = usize::from(source[1..].starts_with('\\'));
+    let c = source[1 + escape..].chars().next()?;
+    let len = 1 + escape + c.len_utf8();
+    source[len..].starts_with('\'').then_some(len + 1)
+}
+
+/// Depth of the delimiters left open at the end of `source`, skipping literals and comments.
+/// A literal left open counts as a delimiter. Gives the index of the line with a closing
+/// delimiter that has no opening one.
+fn open_delimiters(source: &str) -> Result<usize, usize> {
+    let mut depth = 0;
+    let mut index = 0;
+    while let Some(c) = source[index..].chars().next() {
+        let rest = &source[index..];
+        let is_literal = c == '"'
+            || (c == 'r'
+                && rest[1..].trim_start_matches('#').starts_with('"')
+                && !source[..index].ends_with(is_word_char));
+        let len = match c {
+            _ if is_literal => match literal_len(rest) {
+                Some(len) => len,
+                None => return Ok(depth + 1),
+            },
+            // Character literals, unlike lifetimes, end with a quote
+            '\'' => char_literal_len(rest).unwrap_or(1),
+            '/' if rest.starts_with("//") => rest.find('\n').unwrap_or(rest.len()),
+            '{' | '(' | '[' => {
+                depth += 1;
+                1
+            }
+            '}' | ')' | ']' => match depth {
+                0 => return Err(source[..index].matches('\n').count()),
+                _ => {
+                    depth -= 1;
+                    1
+                }
+            },
+            c => c.len_utf8(),
+        };
+        index += len;
+    }
+    Ok(depth)
+}
+
+/// Tokenizes the template line at index `number`, joining the lines after it while a delimiter
+/// is left open, like the `{}` group of a `match` spanning lines. Gives the lines joined.
+pub fn tokenize<'a>(
+    number: usize,
+    line: &str,
+    rest: &mut impl Iterator<Item = (usize, &'a str)>,
+) -> Result<(TokenStream, Vec<&'a str>), String> {
+    let mut source = line.to_string();
+    let mut joined = vec![];
+    loop {
+        match open_delimiters(&source) {
+            Ok(0) => break,
+            Ok(_) => {
+                let (_, next) = rest
+                    .next()
+                    .ok_or_else(|| format!("line {}: unclosed delimiter", number + 1))?;
+                source.push('\n');
+                source.push_str(next);
+                joined.push(next);
+            }
+            Err(offset) => {
+                return Err(format!(
+                    "line {}: unexpected closing delimiter",
+                    number + offset + 1
+                ))
+            }
+        }
+    }
+    match TokenStream::from_str(&source) {
+        Ok(tokens) => Ok((tokens, joined)),
+        Err(_) => Err(format!("line {}: mismatched delimiters", number + 1)),
+    }
+}
+
+pub(crate) fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Length of the string literal at the start of `source`, raw or not.
+pub(crate) fn literal_len(source: &str) -> Option<usize> {
+    if let Some(rest) = source.strip_prefix('r') {
+        let hashes = rest.len() - rest.trim_start_matches('#').len();
+        let body = rest[hashes..].strip_prefix('"')?;
+        let end = body.find(&format!("\"{}", "#".repeat(hashes)))?;
+        return Some(1 + hashes + 1 + end + 1 + hashes);
+    }
+    let mut escaped = false;
+    for (index, c) in source.char_indices().skip(1) {
+        match c {
+            '\\' if !escaped => escaped = true,
+            '"' if !escaped => return Some(index + 1),
+            _ => escaped = false,
+        }
+    }
+    None
+}
+
+/// Length of the delimited group at the start of `source`, with its delimiters.
+fn group_len(source: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut index = 0;
+    while index < source.len() {
+        let rest = &source[index..];
+        let c = rest.chars().next()?;
+        match c {
+            '{' | '(' | '[' => depth += 1,
+            '}' | ')' | ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(index + 1);
+                }
+            }
+            '"' => {
+                index += literal_len(rest)?;
+                continue;
+            }
+            'r' if rest[1..].starts_with(['"', '#'])
+                && !source[..index].ends_with(is_word_char) =>
+            {
+                if let Some(len) = literal_len(rest) {
+                    index += len;
+                    continue;
+                }
+            }
+            _ => (),
+        }
+        index += c.len_utf8();
+    }
+    None
+}
+
+/// Length of the word at the start of `source`, with the dashes joining its parts like in
+/// `http-equiv`.
+fn word_len(source: &str) -> usize {
+    let mut len = 0;
+    loop {
+        len += source[len..]
+            .find(|c| !is_word_char(c))
+            .unwrap_or(source.len() - len);
+        match source[len..].strip_prefix('-') {
+            Some(rest) if rest.starts_with(is_word_char) => len += 1,
+            _ => return len,
+        }
+    }
+}
+
+/// Splits a line into tokens as written: literals, groups with their delimiters, words and
+/// single punctuation characters.
+pub(crate) fn tokens(line: &str) -> Option<Vec<&str>> {
+    let mut res = vec![];
+    let mut rest = line.trim_start();
+    while let Some(c) = rest.chars().next() {
+        let len = if c == '"' || (c == 'r' && rest[1..].starts_with(['"', '#'])) {
+            literal_len(rest)?
+        } else if matches!(c, '{' | '(' | '[') {
+            group_len(rest)?
+        } else if is_word_char(c) {
+            word_len(rest)
+        } else {
+            c.len_utf8()
+        };
+        res.push(&rest[..len]);
+        rest = rest[len..].trim_start();
+    }
+    Some(res)
+}
+
+/// Words of a line joined by dashes, like `http-equiv`, in the order they are written.
+pub(crate) fn dashed_words(line: &str) -> Vec<&str> {
+    tokens(line)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|token| token.starts_with(is_word_char) && token.contains('-'))
+        .collect()
+}
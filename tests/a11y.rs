@@ -0,0 +1,52 @@
+#[test]
+fn test_a11y_lint() -> Result<(), failure::Error> {
+    assert_eq!(
+        tent::html!(
+            r#"
+            lint a11y
+            form
+                h1 "Sign in"
+                h2 "Account"
+                label for="name" "Name"
+                input id="name" type="text"
+                label
+                    "Password"
+                    input type="password"
+                input type="hidden" name="token" value="x"
+                button
+                    img src="/go.png" alt="Go"
+                a href="/help" "Help"
+        "#
+        )
+        .to_string(),
+        [
+            "<form><h1>Sign in</h1><h2>Account</h2>",
+            "<label for=\"name\">Name</label>",
+            "<input id=\"name\" type=\"text\"></input>",
+            "<label>Password<input type=\"password\"></input></label>",
+            "<input type=\"hidden\" name=\"token\" value=\"x\"></input>",
+            "<button><img src=\"/go.png\" alt=\"Go\"></img></button>",
+            "<a href=\"/help\">Help</a>",
+            "</form>"
+        ]
+        .join("")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_a11y_selected_rules() -> Result<(), failure::Error> {
+    assert_eq!(
+        tent::html!(
+            r#"
+            lint a11y(imgAlt)
+            div
+                h3 "Skipped levels are not checked"
+                img src="/a.png" alt=""
+        "#
+        )
+        .to_string(),
+        "<div><h3>Skipped levels are not checked</h3><img src=\"/a.png\" alt=\"\"></img></div>"
+    );
+    Ok(())
+}
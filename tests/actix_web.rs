@@ -0,0 +1,49 @@
+#![cfg(feature = "actix-web")]
+
+use actix_web::http::header;
+use actix_web::{test, web, App};
+use tent::{CssContent, HtmlContent};
+
+async fn page() -> HtmlContent {
+    tent::html!(
+        r#"
+        p.notice "Hello"
+    "#
+    )
+}
+
+async fn styles() -> CssContent {
+    tent::css!(
+        r#"
+        .notice
+            color: "red"
+    "#
+    )
+}
+
+#[actix_web::test]
+async fn test_html_responder() -> Result<(), failure::Error> {
+    let app = test::init_service(App::new().route("/", web::get().to(page))).await;
+    let response = test::call_service(&app, test::TestRequest::get().uri("/").to_request()).await;
+    assert_eq!(
+        response.headers().get(header::CONTENT_TYPE).unwrap(),
+        "text/html; charset=utf-8"
+    );
+    let body = test::read_body(response).await;
+    assert_eq!(body, "<p class=\"notice\">Hello</p>");
+    Ok(())
+}
+
+#[actix_web::test]
+async fn test_css_responder() -> Result<(), failure::Error> {
+    let app = test::init_service(App::new().route("/styles.css", web::get().to(styles))).await;
+    let request = test::TestRequest::get().uri("/styles.css").to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(
+        response.headers().get(header::CONTENT_TYPE).unwrap(),
+        "text/css"
+    );
+    let body = test::read_body(response).await;
+    assert_eq!(body, ".notice {color: red;}");
+    Ok(())
+}
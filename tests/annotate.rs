@@ -0,0 +1,85 @@
+// Annotations are left out of release builds.
+#![cfg(debug_assertions)]
+
+#[test]
+fn test_attribute() -> Result<(), failure::Error> {
+    let line = line!();
+    let content = tent::html!(
+        r#"
+        annotate
+        div
+            p.notice "Hello"
+    "#
+    );
+    assert_eq!(
+        content.to_string(),
+        [
+            format!("<div data-tent-src=\"{}:{}\">", file!(), line + 4),
+            format!(
+                "<p class=\"notice\" data-tent-src=\"{}:{}\">Hello</p>",
+                file!(),
+                line + 5
+            ),
+            "</div>".to_string(),
+        ]
+        .join("")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_comment() -> Result<(), failure::Error> {
+    let line = line!();
+    let content = tent::html!(
+        r#"
+        annotate comment
+        ul
+            li "Item"
+    "#
+    );
+    assert_eq!(
+        content.to_string(),
+        [
+            format!("<!-- tent: {}:{} -->", file!(), line + 4),
+            "<ul>".to_string(),
+            format!("<!-- tent: {}:{} -->", file!(), line + 5),
+            "<li>Item</li></ul>".to_string(),
+        ]
+        .join("")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_template_file() -> Result<(), failure::Error> {
+    let title = "Hi";
+    assert_eq!(
+        tent::html_file!("tests/templates/annotated.tent", title = title)?.to_string(),
+        [
+            "<section data-tent-src=\"tests/templates/annotated.tent:2\">",
+            "<h1 data-tent-src=\"tests/templates/annotated.tent:3\">Hi</h1>",
+            "</section>",
+        ]
+        .join("")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_runtime_comment() -> Result<(), failure::Error> {
+    let mut context = tent::runtime::Context::new();
+    context.insert("item", "One");
+    let template = tent::runtime::Template::load("tests/templates/annotated_comment.tent")?;
+    assert_eq!(
+        template.render(&context)?.to_string(),
+        [
+            "<!-- tent: tests/templates/annotated_comment.tent:2 -->",
+            "<ul>",
+            "<!-- tent: tests/templates/annotated_comment.tent:3 -->",
+            "<li>One</li></ul>",
+        ]
+        .join("")
+    );
+    assert!(tent::runtime::Template::from_source("scoped styles\ndiv").is_err());
+    Ok(())
+}
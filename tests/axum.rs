@@ -0,0 +1,55 @@
+#![cfg(feature = "axum")]
+
+use axum::body::Body;
+use axum::http::{header, Request};
+use axum::routing::get;
+use axum::Router;
+use http_body_util::BodyExt;
+use tent::{CssContent, HtmlContent};
+use tower::ServiceExt;
+
+async fn page() -> HtmlContent {
+    tent::html!(
+        r#"
+        p.notice "Hello"
+    "#
+    )
+}
+
+async fn styles() -> CssContent {
+    tent::css!(
+        r#"
+        .notice
+            color: "red"
+    "#
+    )
+}
+
+async fn get_body(app: Router, uri: &str) -> Result<(String, String), failure::Error> {
+    let response = app
+        .oneshot(Request::builder().uri(uri).body(Body::empty())?)
+        .await?;
+    let content_type = response.headers()[header::CONTENT_TYPE]
+        .to_str()?
+        .to_string();
+    let body = response.into_body().collect().await?.to_bytes();
+    Ok((content_type, String::from_utf8(body.to_vec())?))
+}
+
+#[tokio::test]
+async fn test_html_response() -> Result<(), failure::Error> {
+    let app = Router::new().route("/", get(page));
+    let (content_type, body) = get_body(app, "/").await?;
+    assert_eq!(content_type, "text/html; charset=utf-8");
+    assert_eq!(body, "<p class=\"notice\">Hello</p>");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_css_response() -> Result<(), failure::Error> {
+    let app = Router::new().route("/styles.css", get(styles));
+    let (content_type, body) = get_body(app, "/styles.css").await?;
+    assert_eq!(content_type, "text/css");
+    assert_eq!(body, ".notice {color: red;}");
+    Ok(())
+}
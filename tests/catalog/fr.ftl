@@ -0,0 +1,3 @@
+greeting = Bonjour, { $name } !
+nav =
+    .home = Accueil
@@ -0,0 +1,36 @@
+#[test]
+fn test_check_report() -> Result<(), failure::Error> {
+    let root = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/check");
+    let report = tent::check::scan(root.as_ref(), &["src"])?;
+    assert_eq!(report.unused_selectors(), [".unused", "#gone", ".wide"]);
+    assert_eq!(report.undefined_classes(), ["also", "card", "missing"]);
+    assert!(!report.is_clean());
+    assert_eq!(
+        report.to_string(),
+        [
+            "unused css rule `.unused`",
+            "unused css rule `#gone`",
+            "unused css rule `.wide`",
+            "class `also` is not defined by any stylesheet",
+            "class `card` is not defined by any stylesheet",
+            "class `missing` is not defined by any stylesheet",
+            ""
+        ]
+        .join("\n")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_check_errors() -> Result<(), failure::Error> {
+    let root = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/check");
+    let report = tent::check::scan(root.as_ref(), &["broken"])?;
+    assert_eq!(report.errors().len(), 2);
+    assert!(report.errors()[0].starts_with("broken/pages.rs: line "));
+    assert!(report.errors()[1].starts_with("templates/gone.tent: "));
+    // Classes of the template which failed are unknown, so no rule is reported as unused
+    assert!(report.unused_selectors().is_empty());
+    assert!(report.undefined_classes().is_empty());
+    assert!(!report.is_clean());
+    Ok(())
+}
@@ -0,0 +1,25 @@
+// Scanned by `tests/check.rs`, not compiled.
+
+fn styles() -> tent::CssContent {
+    tent::css!(
+        r#"
+        .notice
+            width: "400px"
+        @media "(max-width: 600px)"
+            .notice
+                width: "100%"
+    "#
+    )
+}
+
+fn page() -> tent::HtmlContent {
+    tent::html!(
+        r#"
+        .notice
+            p = ;
+    "#
+    )
+}
+
+#[tent::template("templates/gone.tent")]
+fn gone() -> tent::HtmlContent {}
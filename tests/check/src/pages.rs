@@ -0,0 +1,35 @@
+// Scanned by `tests/check.rs`, not compiled.
+
+fn styles() -> tent::CssContent {
+    tent::css!(
+        r#"
+        .notice
+            width: "400px"
+            .head
+                fontSize: "4em"
+        .unused
+            margin: "0"
+        #main
+            width: "100%"
+        #gone
+            width: "0"
+        @media "(max-width: 600px)"
+            .notice
+                width: "100%"
+            .wide
+                width: "100%"
+    "#
+    )
+}
+
+fn page() -> tent::HtmlContent {
+    tent::html!(
+        r#"
+        .notice id="main"
+            span.head class="missing also" "Head"
+    "#
+    )
+}
+
+#[tent::template("templates/card.tent")]
+fn card(title: &str) -> tent::HtmlContent {}
@@ -0,0 +1,12 @@
+#[test]
+fn test_compile_fail() -> Result<(), failure::Error> {
+    // Keys looked up by the cases are checked against these catalogs
+    std::env::set_var(
+        "TENT_CATALOG",
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/catalog"),
+    );
+    let cases = trybuild::TestCases::new();
+    cases.pass("tests/ui/pass/*.rs");
+    cases.compile_fail("tests/ui/*.rs");
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use tent::{Component, HtmlContent};
+
+struct Card {
+    title: String,
+    children: HtmlContent,
+}
+
+impl Component for Card {
+    fn render(self) -> HtmlContent {
+        tent::html!(
+            r#"
+            .card
+                h2 {self.title}
+                {self.children}
+        "#
+        )
+    }
+}
+
+struct Badge {
+    label: &'static str,
+    count: u32,
+}
+
+impl Component for Badge {
+    fn render(self) -> HtmlContent {
+        tent::html!(
+            r#"
+            span.badge dataCount={self.count} {self.label}
+        "#
+        )
+    }
+}
+
+#[test]
+fn test_component() -> Result<(), failure::Error> {
+    let title = "Hello";
+    assert_eq!(
+        tent::html!(
+            r#"
+            div
+                @Card title={title}
+                    p "First"
+                    @Badge label="new" count={3u32}
+        "#
+        )
+        .to_string(),
+        [
+            "<div><div class=\"card\"><h2>Hello</h2>",
+            "<p>First</p>",
+            "<span class=\"badge\" data-count=\"3\">new</span>",
+            "</div></div>"
+        ]
+        .join("")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_component_inline_children() -> Result<(), failure::Error> {
+    assert_eq!(
+        tent::html!(
+            r#"
+            @Card title="Inline" "Text"
+        "#
+        )
+        .to_string(),
+        "<div class=\"card\"><h2>Inline</h2>Text</div>"
+    );
+    Ok(())
+}
@@ -0,0 +1,191 @@
+use tent::runtime::{Context, Stylesheet, Template};
+
+#[test]
+fn test_html() -> Result<(), failure::Error> {
+    let source = [
+        "<!DOCTYPE html>",
+        "<html>",
+        "  <body class=\"page dark-mode\">",
+        "    <!-- Navigation -->",
+        "    <nav id=\"top\" data-user-id=\"7\" aria-label=\"Main\" @click=\"go\">",
+        "      <a href=\"/\">Home</a> <a href=\"/about\">About</a>",
+        "    </nav>",
+        "    <p>Hello <b>world</b>!",
+        "    <p>Second<br>line</p>",
+        "    <input type=checkbox checked>",
+        "    <svg viewBox=\"0 0 10 10\"><circle stroke-width=\"2\" r=\"5\"/></svg>",
+        "    <script>",
+        "      if (a < b) {",
+        "        go();",
+        "      }",
+        "    </script>",
+        "  </body>",
+        "</html>",
+    ]
+    .join("\n");
+    let converted = tent::convert::html(&source).map_err(failure::err_msg)?;
+    assert_eq!(
+        converted,
+        [
+            "html",
+            "    body.page.{\"dark-mode\"}",
+            "        // Navigation",
+            "        nav id=\"top\" dataUserId=\"7\" ariaLabel=\"Main\" \"@click\"=\"go\"",
+            "            a href=\"/\" \"Home\"",
+            "            \" \"",
+            "            a href=\"/about\" \"About\"",
+            "        p",
+            "            \"Hello \"",
+            "            b \"world\"",
+            "            \"!\"",
+            "        p",
+            "            \"Second\"",
+            "            br",
+            "            \"line\"",
+            "        input type=\"checkbox\" checked=\"\"",
+            "        svg viewBox=\"0 0 10 10\"",
+            "            circle strokeWidth=\"2\" r=\"5\"",
+            "        script",
+            "            if (a < b) {",
+            "              go();",
+            "            }",
+        ]
+        .join("\n")
+    );
+
+    let template = Template::from_source(&converted)?;
+    assert_eq!(
+        template.render(&Context::new())?.to_string(),
+        [
+            "<html><body class=\"page dark-mode\">",
+            "<nav id=\"top\" data-user-id=\"7\" aria-label=\"Main\" @click=\"go\">",
+            "<a href=\"/\">Home</a> <a href=\"/about\">About</a></nav>",
+            "<p>Hello <b>world</b>!</p>",
+            "<p>Second<br></br>line</p>",
+            "<input type=\"checkbox\" checked=\"\"></input>",
+            "<svg viewBox=\"0 0 10 10\"><circle stroke-width=\"2\" r=\"5\"></circle></svg>",
+            "<script>if (a < b) {\n  go();\n}</script>",
+            "</body></html>",
+        ]
+        .join("")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_html_preformatted() -> Result<(), failure::Error> {
+    let source = "<div>\n  <pre>  a\n  b</pre>\n  <title>A &amp; B</title>\n</div>";
+    assert_eq!(
+        tent::convert::html(source).map_err(failure::err_msg)?,
+        ["div", "    pre \"  a\\n  b\"", "    title \"A &amp; B\"",].join("\n")
+    );
+    assert_eq!(
+        tent::convert::html("<my-widget data-id=\"1\">Hi</my-widget>").map_err(failure::err_msg)?,
+        "my-widget dataId=\"1\" \"Hi\""
+    );
+    assert!(tent::convert::html("<my-2widget></my-2widget>").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_css() -> Result<(), failure::Error> {
+    let source = [
+        "/* Navigation */",
+        ".nav { display: flex; }",
+        ".nav a { color: #333; font-size: 1.2em }",
+        ".nav a:hover { color: red; }",
+        ".nav>li { list-style: none; }",
+        "h1, h2 { margin: 0; }",
+        ":root { --accent: #f00; -webkit-user-select: none; }",
+        "@font-face { font-family: \"myfont\"; src: url('/font.woff'); }",
+    ]
+    .join("\n");
+    let converted = tent::convert::css(&source).map_err(failure::err_msg)?;
+    assert_eq!(
+        converted,
+        [
+            ".nav",
+            "    display: \"flex\"",
+            "    a",
+            "        color: \"#333\"",
+            "        fontSize: \"1.2em\"",
+            "    \"a:hover\"",
+            "        color: \"red\"",
+            "    \"> li\"",
+            "        listStyle: \"none\"",
+            "",
+            "\"h1, h2\"",
+            "    margin: \"0\"",
+            "",
+            "\":root\"",
+            "    \"--accent\": \"#f00\"",
+            "    WebkitUserSelect: \"none\"",
+            "",
+            "@fontFace",
+            "    fontFamily: \"\\\"myfont\\\"\"",
+            "    src: \"url('/font.woff')\"",
+        ]
+        .join("\n")
+    );
+
+    let stylesheet = Stylesheet::from_source(&converted)?;
+    assert_eq!(
+        stylesheet.render(&Context::new())?.to_string(),
+        [
+            ".nav a {color: #333;font-size: 1.2em;}",
+            ".nav a:hover {color: red;}",
+            ".nav > li {list-style: none;}",
+            ".nav {display: flex;}",
+            "h1, h2 {margin: 0;}",
+            ":root {--accent: #f00;-webkit-user-select: none;}",
+            "@font-face {font-family: \"myfont\";src: url('/font.woff');}",
+        ]
+        .join("")
+    );
+    assert!(tent::convert::css("@keyframes spin { to { rotate: 1turn } }").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_css_media() -> Result<(), failure::Error> {
+    let source = [
+        ".card { width: 400px; }",
+        "@media (max-width: 600px) {",
+        "    .card { width: 100%; }",
+        "    .card h2 { font-size: 1em; }",
+        "}",
+        "@media print { .card { display: none } }",
+    ]
+    .join("\n");
+    let converted = tent::convert::css(&source).map_err(failure::err_msg)?;
+    assert_eq!(
+        converted,
+        [
+            ".card",
+            "    width: \"400px\"",
+            "",
+            "@media \"(max-width: 600px)\"",
+            "    .card",
+            "        width: \"100%\"",
+            "        h2",
+            "            fontSize: \"1em\"",
+            "",
+            "@media \"print\"",
+            "    .card",
+            "        display: \"none\"",
+        ]
+        .join("\n")
+    );
+
+    let stylesheet = Stylesheet::from_source(&converted)?;
+    assert_eq!(
+        stylesheet.render(&Context::new())?.to_string(),
+        [
+            ".card {width: 400px;}",
+            "@media (max-width: 600px) {.card h2 {font-size: 1em;}.card {width: 100%;}}",
+            "@media print {.card {display: none;}}",
+        ]
+        .join("")
+    );
+    Ok(())
+}
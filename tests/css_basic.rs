@@ -1,3 +1,5 @@
+#![allow(clippy::useless_vec)]
+
 #[test]
 fn test_simple() -> Result<(), failure::Error> {
     assert_eq!(
@@ -54,3 +56,72 @@ fn test_font() -> Result<(), failure::Error> {
     );
     Ok(())
 }
+
+#[test]
+fn test_quoted_names() -> Result<(), failure::Error> {
+    assert_eq!(
+        tent::css!(
+            r#"
+            .nav
+                "a:hover"
+                    color: "red"
+                "> li"
+                    "--gap": "1em"
+        "#
+        )
+        .to_string(),
+        [
+            ".nav a:hover {color: red;}",
+            ".nav > li {--gap: 1em;}",
+            ".nav {}",
+        ]
+        .join(""),
+    );
+    Ok(())
+}
+
+#[test]
+fn test_multiline_expressions() -> Result<(), failure::Error> {
+    let wide = true;
+    assert_eq!(
+        tent::css!(
+            r#"
+            .notice
+                width: {if wide {
+                    "800px"
+                } else {
+                    "400px"
+                }}
+                height: "300px"
+        "#
+        )
+        .to_string(),
+        ".notice {width: 800px;height: 300px;}",
+    );
+    Ok(())
+}
+
+#[test]
+fn test_media() -> Result<(), failure::Error> {
+    assert_eq!(
+        tent::css!(
+            r#"
+            .card
+                width: "400px"
+                @media "(max-width: 600px)"
+                    width: "100%"
+            @media "print"
+                .card
+                    display: "none"
+        "#
+        )
+        .to_string(),
+        [
+            ".card {width: 400px;}",
+            "@media (max-width: 600px) {.card {width: 100%;}}",
+            "@media print {.card {display: none;}}",
+        ]
+        .join(""),
+    );
+    Ok(())
+}
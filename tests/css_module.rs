@@ -0,0 +1,79 @@
+#[test]
+fn test_css_module() -> Result<(), failure::Error> {
+    let styles = tent::css!(
+        module,
+        r#"
+        #idTest
+            width: "100px"
+        .notice
+            width: "400px"
+            .head
+                fontSize: "4em"
+        .type
+            margin: "0"
+    "#
+    );
+    assert_eq!(styles.notice, "notice");
+    assert_eq!(styles.head, "head");
+    assert_eq!(styles.idTest, "idTest");
+    assert_eq!(styles.r#type, "type");
+    assert_eq!(
+        styles.to_string(),
+        "#idTest {width: 100px;}.notice .head {font-size: 4em;}.notice {width: 400px;}.type {margin: 0;}"
+    );
+    assert_eq!(
+        tent::html!(
+            r#"
+            .{styles.notice}.extra id={styles.idTest}
+                span.{styles.head} "Head"
+        "#
+        )
+        .to_string(),
+        "<div class=\"notice extra\" id=\"idTest\"><span class=\"head\">Head</span></div>"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_scoped_css_module() -> Result<(), failure::Error> {
+    let styles = tent::css!(scoped, module, ".card\n    width: \"1px\"");
+    assert!(styles.card.starts_with("card-"));
+    assert_eq!(
+        styles.to_string(),
+        format!(".{} {{width: 1px;}}", styles.card)
+    );
+    Ok(())
+}
+
+tent::css!(
+    module pub Styles,
+    r#"
+    .card
+        width: "400px"
+    #main
+        margin: "0"
+"#
+);
+
+fn card(styles: &Styles) -> tent::HtmlContent {
+    tent::html!(
+        r#"
+        .{styles.card} id={styles.main} "Card"
+    "#
+    )
+}
+
+#[test]
+fn test_named_css_module() -> Result<(), failure::Error> {
+    let styles = Styles::new();
+    assert_eq!(styles.card, "card");
+    assert_eq!(
+        styles.to_string(),
+        ".card {width: 400px;}#main {margin: 0;}"
+    );
+    assert_eq!(
+        card(&styles).to_string(),
+        "<div class=\"card\" id=\"main\">Card</div>"
+    );
+    Ok(())
+}
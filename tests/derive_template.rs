@@ -0,0 +1,62 @@
+use tent::Template;
+
+struct User {
+    name: String,
+    age: u32,
+}
+
+#[derive(tent::Template)]
+#[template(path = "tests/templates/user.tent")]
+struct UserPage {
+    user: User,
+}
+
+#[test]
+fn test_derive_template() -> Result<(), failure::Error> {
+    let page = UserPage {
+        user: User {
+            name: "inkyu".to_string(),
+            age: 30,
+        },
+    };
+    let expected = "<div class=\"user\"><h1>inkyu</h1><span class=\"age\">30</span></div>";
+    assert_eq!(page.to_string(), expected);
+    assert_eq!(page.render_string(), expected);
+    assert_eq!(
+        page.size_hint(),
+        "<div class=\"user\"><h1></h1><span class=\"age\"></span></div>".len()
+    );
+    Ok(())
+}
+
+#[derive(tent::Template)]
+#[template(path = "tests/templates/kind.tent")]
+struct Kind {
+    r#type: &'static str,
+}
+
+#[test]
+fn test_raw_field() -> Result<(), failure::Error> {
+    let kind = Kind { r#type: "button" };
+    assert_eq!(kind.to_string(), "<span class=\"kind\">button</span>");
+    Ok(())
+}
+
+fn label(name: &&'static str) -> String {
+    format!("<li>{}</li>", name)
+}
+
+#[derive(tent::Template)]
+#[template(path = "tests/templates/labels.tent")]
+struct Labels {
+    items: Vec<&'static str>,
+}
+
+#[test]
+fn test_fn_item() -> Result<(), failure::Error> {
+    let labels = Labels {
+        items: vec!["a", "b"],
+    };
+    assert_eq!(labels.to_string(), "<ul><li>a</li><li>b</li></ul>");
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use tent::feed::{Date, Entry, Feed};
+
+fn feed() -> Feed {
+    Feed {
+        title: "News & Notes".to_string(),
+        link: "https://example.com/".to_string(),
+        id: "https://example.com/feed.xml".to_string(),
+        description: "Latest posts".to_string(),
+        updated: Date::from_timestamp(1709285400),
+        author: Some("Inkyu".to_string()),
+        entries: vec![Entry {
+            title: "Hello".to_string(),
+            link: "https://example.com/hello".to_string(),
+            id: "https://example.com/hello".to_string(),
+            updated: Date::from_timestamp(951782400),
+            summary: Some(tent::html!(
+                r#"
+                p "Hi " {"<all>"}
+            "#
+            )),
+            author: None,
+        }],
+    }
+}
+
+#[test]
+fn test_dates() -> Result<(), failure::Error> {
+    assert_eq!(Date::from_timestamp(0).to_rfc3339(), "1970-01-01T00:00:00Z");
+    assert_eq!(
+        Date::from_timestamp(1709285400).to_rfc3339(),
+        "2024-03-01T09:30:00Z"
+    );
+    assert_eq!(
+        Date::from_timestamp(1709285400).to_rfc822(),
+        "Fri, 01 Mar 2024 09:30:00 +0000"
+    );
+    // Leap day of a year divisible by 400
+    assert_eq!(
+        Date::from_timestamp(951782400).to_rfc822(),
+        "Tue, 29 Feb 2000 00:00:00 +0000"
+    );
+    assert_eq!(
+        Date::from_timestamp(-1).to_rfc3339(),
+        "1969-12-31T23:59:59Z"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_atom() -> Result<(), failure::Error> {
+    assert_eq!(
+        feed().to_atom().to_string(),
+        [
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?>",
+            "<feed xmlns=\"http://www.w3.org/2005/Atom\">",
+            "<title>News &amp; Notes</title>",
+            "<link href=\"https://example.com/\"/>",
+            "<id>https://example.com/feed.xml</id>",
+            "<updated>2024-03-01T09:30:00Z</updated>",
+            "<subtitle>Latest posts</subtitle>",
+            "<author><name>Inkyu</name></author>",
+            "<entry>",
+            "<title>Hello</title>",
+            "<link href=\"https://example.com/hello\"/>",
+            "<id>https://example.com/hello</id>",
+            "<updated>2000-02-29T00:00:00Z</updated>",
+            "<summary type=\"html\">&lt;p&gt;Hi &lt;all&gt;&lt;/p&gt;</summary>",
+            "</entry>",
+            "</feed>",
+        ]
+        .join("")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_atom_author() -> Result<(), failure::Error> {
+    let mut feed = feed();
+    feed.author = None;
+    assert!(feed.to_atom().to_string().contains(
+        "<subtitle>Latest posts</subtitle><author><name>News &amp; Notes</name></author>"
+    ));
+
+    feed.entries[0].author = Some("Jin".to_string());
+    let atom = feed.to_atom().to_string();
+    assert!(!atom.contains("<name>News &amp; Notes</name>"));
+    assert!(atom.contains("<author><name>Jin</name></author>"));
+    Ok(())
+}
+
+#[test]
+fn test_rss() -> Result<(), failure::Error> {
+    assert_eq!(
+        feed().to_rss().to_string(),
+        [
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?>",
+            "<rss version=\"2.0\"><channel>",
+            "<title>News &amp; Notes</title>",
+            "<link>https://example.com/</link>",
+            "<description>Latest posts</description>",
+            "<lastBuildDate>Fri, 01 Mar 2024 09:30:00 +0000</lastBuildDate>",
+            "<item>",
+            "<title>Hello</title>",
+            "<link>https://example.com/hello</link>",
+            "<guid isPermaLink=\"true\">https://example.com/hello</guid>",
+            "<pubDate>Tue, 29 Feb 2000 00:00:00 +0000</pubDate>",
+            "<description>&lt;p&gt;Hi &lt;all&gt;&lt;/p&gt;</description>",
+            "</item>",
+            "</channel></rss>",
+        ]
+        .join("")
+    );
+    Ok(())
+}
@@ -0,0 +1,166 @@
+#[test]
+fn test_html() -> Result<(), failure::Error> {
+    let source = [
+        "  lint a11y",
+        "  div",
+        "   span \"Text\"  title = \"Hi\" .note id=\"x\"",
+        "   a  href={url}   .link{ \"Go\" }",
+        "",
+        "",
+        "   ul",
+        "         li   {item}",
+        "  script",
+        "        if (a) {",
+        "            go();",
+        "        }",
+    ]
+    .join("\n");
+    assert_eq!(
+        tent::format::html(&source),
+        [
+            "lint a11y",
+            "div",
+            "    span.note id=\"x\" title=\"Hi\" \"Text\"",
+            "    a.link href={url} { \"Go\" }",
+            "",
+            "    ul",
+            "        li {item}",
+            "script",
+            "    if (a) {",
+            "        go();",
+            "    }",
+        ]
+        .join("\n")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_css() -> Result<(), failure::Error> {
+    let source = [
+        ".notice",
+        "  width :\"400px\"",
+        "  .head",
+        "       fontSize:   \"4em\"",
+    ]
+    .join("\n");
+    assert_eq!(
+        tent::format::css(&source),
+        [
+            ".notice",
+            "    width: \"400px\"",
+            "    .head",
+            "        fontSize: \"4em\""
+        ]
+        .join("\n")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_css_quoted_names() -> Result<(), failure::Error> {
+    let source = [".nav", "  \"a:hover\"", "     \"--gap\" :\"1em\""].join("\n");
+    assert_eq!(
+        tent::format::css(&source),
+        [".nav", "    \"a:hover\"", "        \"--gap\": \"1em\""].join("\n")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_multiline_expressions() -> Result<(), failure::Error> {
+    let source = [
+        "div",
+        "  span {match count {",
+        "      0 => \"none\",",
+        "      _ => \"some\",",
+        "  }}",
+        "  p   \"Done\"",
+    ]
+    .join("\n");
+    assert_eq!(
+        tent::format::html(&source),
+        [
+            "div",
+            "    span {match count {",
+            "        0 => \"none\",",
+            "        _ => \"some\",",
+            "    }}",
+            "    p \"Done\"",
+        ]
+        .join("\n")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_rust() -> Result<(), failure::Error> {
+    let source = [
+        "let page = tent::html!(",
+        "    r#\"",
+        "    div",
+        "      p  \"Hi\"",
+        "\"#",
+        ");",
+        "let styles = css!(scoped, r#\"",
+        "        .a",
+        "          color:\"red\"",
+        "    \"#);",
+        "let inline = html!(\"p  \\\"Hi\\\"\");",
+    ]
+    .join("\n");
+    assert_eq!(
+        tent::format::rust(&source),
+        [
+            "let page = tent::html!(",
+            "    r#\"",
+            "    div",
+            "        p \"Hi\"",
+            "\"#",
+            ");",
+            "let styles = css!(scoped, r#\"",
+            "        .a",
+            "            color: \"red\"",
+            "    \"#);",
+            "let inline = html!(\"p  \\\"Hi\\\"\");",
+        ]
+        .join("\n")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_rust_idempotent() -> Result<(), failure::Error> {
+    let source = [
+        "let page = html!(r#\"",
+        "                div",
+        "                  p \"Hi\"",
+        "            \"#);",
+        "let broken = html!(r#\"",
+        "                div",
+        "                        p \"Hi\"",
+        "                      span",
+        "\t\t\"#);",
+    ]
+    .join("\n");
+    let once = tent::format::rust(&source);
+    assert_eq!(tent::format::rust(&once), once);
+    // The second literal dedents to a level it never opened, so it stays as written
+    assert!(once.ends_with(&source[source.find("let broken").unwrap_or_default()..]));
+    Ok(())
+}
+
+#[test]
+fn test_dashed_names() -> Result<(), failure::Error> {
+    assert_eq!(
+        tent::format::html(
+            "web-app   version=\"4.0\"\n  servlet-name  load-on-startup=\"1\" \"main\""
+        ),
+        [
+            "web-app version=\"4.0\"",
+            "    servlet-name load-on-startup=\"1\" \"main\""
+        ]
+        .join("\n")
+    );
+    Ok(())
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::useless_vec)]
+
 #[test]
 fn test_simple() -> Result<(), failure::Error> {
     assert_eq!(
@@ -94,3 +96,168 @@ fn test_svg_node() -> Result<(), failure::Error> {
     );
     Ok(())
 }
+
+#[test]
+fn test_svg_attributes() -> Result<(), failure::Error> {
+    assert_eq!(
+        tent::html!(
+            r#"
+            div dataMarkerWidth="1" "someAttr"="kept"
+                svg preserveAspectRatio="none"
+                    lineargradient gradientTransform="rotate(90)"
+                    filter
+                        feGaussianBlur stdDeviation="2"
+                    path strokeWidth="2" markerWidth="3"
+                    foreignObject
+                        p dataMarkerWidth="4"
+        "#
+        )
+        .to_string(),
+        [
+            "<div data-marker-width=\"1\" someAttr=\"kept\">",
+            "<svg preserveAspectRatio=\"none\">",
+            "<linearGradient gradientTransform=\"rotate(90)\"></linearGradient>",
+            "<filter><feGaussianBlur stdDeviation=\"2\"></feGaussianBlur></filter>",
+            "<path stroke-width=\"2\" markerWidth=\"3\"></path>",
+            "<foreignObject><p data-marker-width=\"4\"></p></foreignObject>",
+            "</svg></div>"
+        ]
+        .join("")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_escaped_quoted_names() -> Result<(), failure::Error> {
+    assert_eq!(
+        tent::html!(
+            r#"
+            div "data-a\"b"="1" "data-c\\d"="2"
+        "#
+        )
+        .to_string(),
+        "<div data-a\"b=\"1\" data-c\\d=\"2\"></div>",
+    );
+    Ok(())
+}
+
+#[test]
+fn test_namespaced_attributes() -> Result<(), failure::Error> {
+    assert_eq!(
+        tent::html!(
+            r##"
+            html xml:lang="en"
+                body
+                    svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink"
+                        use xlink:href="#icon" xlink:arcRole="x"
+        "##
+        )
+        .to_string(),
+        [
+            "<html xml:lang=\"en\"><body>",
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" xmlns:xlink=\"http://www.w3.org/1999/xlink\">",
+            "<use xlink:href=\"#icon\" xlink:arcRole=\"x\"></use>",
+            "</svg>",
+            "</body></html>"
+        ]
+        .join("")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_raw_text() -> Result<(), failure::Error> {
+    let stylesheet = tent::css!(
+        r#"
+        .notice
+            width: "400px"
+    "#
+    );
+    assert_eq!(
+        tent::html!(
+            r#"
+            html
+                head
+                    style {stylesheet}
+                    style media="print"
+                        body { color: black; }
+
+                        .notice > p::after { content: "'"; }
+                    script type="module"
+                        if (window.x < 1) {
+                            console.log('hi');
+                        }
+                body
+        "#
+        )
+        .to_string(),
+        [
+            "<html><head>",
+            "<style>.notice {width: 400px;}</style>",
+            "<style media=\"print\">body { color: black; }\n\n",
+            ".notice > p::after { content: \"'\"; }</style>",
+            "<script type=\"module\">if (window.x < 1) {\n",
+            "    console.log('hi');\n",
+            "}</script>",
+            "</head><body></body></html>"
+        ]
+        .join("")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_multiline_expressions() -> Result<(), failure::Error> {
+    let count = 2;
+    let items = ["a", "b", "c"];
+    assert_eq!(
+        tent::html!(
+            r#"
+            div
+                span {match count {
+                    0 => "none",
+                    1 => "one",
+                    _ => "many",
+                }}
+                a href={items
+                    .iter()
+                    .map(|item| format!("/{}", item))
+                    .collect::<Vec<_>>()
+                    .join(",")} "Items"
+                p "Done"
+        "#
+        )
+        .to_string(),
+        [
+            "<div>",
+            "<span>many</span>",
+            "<a href=\"/a,/b,/c\">Items</a>",
+            "<p>Done</p>",
+            "</div>",
+        ]
+        .join("")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dashed_names() -> Result<(), failure::Error> {
+    assert_eq!(
+        tent::html!(
+            r#"
+            div
+                my-widget data-id="1" "Hi"
+                meta http-equiv="refresh"
+        "#
+        )
+        .to_string(),
+        [
+            "<div>",
+            "<my-widget data-id=\"1\">Hi</my-widget>",
+            "<meta http-equiv=\"refresh\"></meta>",
+            "</div>",
+        ]
+        .join("")
+    );
+    Ok(())
+}
@@ -0,0 +1,29 @@
+// Built with and without the `dev` feature, which interprets the template instead
+#[test]
+fn test_html_file() -> Result<(), failure::Error> {
+    #[derive(tent::runtime::ToValue)]
+    struct User {
+        name: &'static str,
+    }
+    let user = User { name: "inkyu" };
+    assert_eq!(
+        tent::html_file!("tests/templates/page.tent", title = "Title", user = user)?.to_string(),
+        [
+            "<html><body>",
+            "<h1 class=\"title\">Title</h1>",
+            "<span data=\"inkyu\">HELLO!</span>",
+            "</body></html>"
+        ]
+        .join("")
+    );
+    Ok(())
+}
+
+#[cfg(feature = "dev")]
+#[test]
+fn test_html_file_error() -> Result<(), failure::Error> {
+    // Compiled, the missing `user` wouldn't build
+    let error = tent::html_file!("tests/templates/page.tent", title = "Title").unwrap_err();
+    assert_eq!(error.to_string(), "undefined variable `user`");
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use std::collections::HashMap;
+
+use tent::{HtmlContent, Translator};
+
+fn messages() -> HashMap<String, String> {
+    [
+        ("nav.home", "Startseite"),
+        ("greeting", "Hallo, {name}!"),
+        ("search", "Suchen"),
+    ]
+    .iter()
+    .map(|(key, message)| (key.to_string(), message.to_string()))
+    .collect()
+}
+
+#[test]
+fn test_translate() -> Result<(), failure::Error> {
+    let tr = messages();
+    let user = "Inkyu";
+    assert_eq!(
+        tent::html!(
+            r#"
+            translate tr
+            nav
+                a href="/" {t!("nav.home")}
+                p {t!("greeting", name = user)}
+                input type="search" placeholder={t!("search")}
+                p {t!("missing")}
+        "#
+        )
+        .to_string(),
+        [
+            "<nav>",
+            "<a href=\"/\">Startseite</a>",
+            "<p>Hallo, Inkyu!</p>",
+            "<input type=\"search\" placeholder=\"Suchen\"></input>",
+            "<p>missing</p>",
+            "</nav>",
+        ]
+        .join("")
+    );
+    Ok(())
+}
+
+#[tent::template("tests/templates/greeting.tent")]
+fn greeting(tr: &dyn Translator, name: &str) -> HtmlContent {}
+
+#[test]
+fn test_template_translator() -> Result<(), failure::Error> {
+    assert_eq!(
+        greeting(&messages(), "Inkyu").to_string(),
+        "<h1>Hallo, Inkyu!</h1>"
+    );
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use tent::runtime::{Context, Stylesheet, Template};
+
+#[test]
+fn test_tabs() -> Result<(), failure::Error> {
+    assert_eq!(
+        tent::html!("\tdiv\n\t\tspan \"Tabbed\"\n\t\ta href=\"/\" \"Link\"").to_string(),
+        "<div><span>Tabbed</span><a href=\"/\">Link</a></div>"
+    );
+    assert_eq!(
+        tent::css!("\t.note\n\t\twidth: \"4em\"").to_string(),
+        ".note {width: 4em;}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_relative_to_least_indented() -> Result<(), failure::Error> {
+    let template = Template::from_source("\t\t\tdiv\n\t\t\t\tspan \"Deep\"")?;
+    assert_eq!(
+        template.render(&Context::new())?.to_string(),
+        "<div><span>Deep</span></div>"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_mixed_tabs_and_spaces() -> Result<(), failure::Error> {
+    let error = Template::from_source("div\n    span \"Spaces\"\n\tspan \"Tab\"").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "cannot parse template: line 3: indentation mixes tabs and spaces"
+    );
+    let error = Stylesheet::from_source(".note\n \twidth: \"4em\"").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "cannot parse template: line 2: indentation mixes tabs and spaces"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_unaligned_dedent() -> Result<(), failure::Error> {
+    let source = ["div", "    ul", "        li \"Item\"", "      p \"Lost\""].join("\n");
+    let error = Template::from_source(&source).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "cannot parse template: line 4: dedent doesn't line up with any enclosing level"
+    );
+    let source = [
+        ".note",
+        "    .head",
+        "        width: \"4em\"",
+        "  height: \"2em\"",
+    ]
+    .join("\n");
+    assert!(Stylesheet::from_source(&source).is_err());
+    assert_eq!(tent::format::css(&source), source);
+    Ok(())
+}
@@ -0,0 +1,106 @@
+#[test]
+fn test_inline() -> Result<(), failure::Error> {
+    let styles = tent::css!(
+        r#"
+        p
+            color: "black"
+            margin: "0"
+        .notice
+            color: "red"
+            .head
+                fontWeight: "bold"
+        #main
+            color: "blue"
+    "#
+    );
+    let content = tent::html!(
+        r#"
+        div
+            p.notice id="main" "Hello"
+            p.notice style="margin: 4px" "World"
+            .notice
+                span.head "Head"
+            span.head "Not in a notice"
+    "#
+    );
+    assert_eq!(
+        tent::inline_css(&content, &styles).to_string(),
+        [
+            "<div>",
+            "<p class=\"notice\" id=\"main\" style=\"color: blue; margin: 0;\">Hello</p>",
+            "<p class=\"notice\" style=\"color: red; margin: 4px;\">World</p>",
+            "<div class=\"notice\" style=\"color: red;\">",
+            "<span class=\"head\" style=\"font-weight: bold;\">Head</span>",
+            "</div>",
+            "<span class=\"head\">Not in a notice</span>",
+            "</div>",
+        ]
+        .join("")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_retained_rules() -> Result<(), failure::Error> {
+    let styles = tent::css!(
+        r#"
+        @fontFace
+            fontFamily: "myfont"
+        body
+            fontFamily: "myfont"
+    "#
+    );
+    let content = tent::html!(
+        r#"
+        html
+            head
+                title "Mail"
+            body "Hello"
+    "#
+    );
+    assert_eq!(
+        tent::inline_css(&content, &styles).to_string(),
+        [
+            "<html><head><title>Mail</title>",
+            "<style>@font-face {font-family: myfont;}</style>",
+            "</head>",
+            "<body style=\"font-family: myfont;\">Hello</body>",
+            "</html>",
+        ]
+        .join("")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_retained_media() -> Result<(), failure::Error> {
+    let styles = tent::css!(
+        r#"
+        .card
+            width: "400px"
+        @media "(max-width: 600px)"
+            .card
+                width: "100%"
+    "#
+    );
+    let content = tent::html!(
+        r#"
+        html
+            head
+                title "Mail"
+            body.card "Hello"
+    "#
+    );
+    assert_eq!(
+        tent::inline_css(&content, &styles).to_string(),
+        [
+            "<html><head><title>Mail</title>",
+            "<style>@media (max-width: 600px) {.card {width: 100%;}}</style>",
+            "</head>",
+            "<body class=\"card\" style=\"width: 400px;\">Hello</body>",
+            "</html>",
+        ]
+        .join("")
+    );
+    Ok(())
+}
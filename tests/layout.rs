@@ -0,0 +1,64 @@
+use tent::runtime::{Context, Template};
+
+#[test]
+fn test_extends() -> Result<(), failure::Error> {
+    assert_eq!(
+        tent::html!(
+            r#"
+            extends "tests/templates/layout.tent"
+                block title
+                    "Home"
+                block content
+                    p "Hi"
+        "#
+        )
+        .to_string(),
+        [
+            "<html><head><title>Home</title></head><body>",
+            "<header>Header</header>",
+            "<p>Hi</p>",
+            "<footer>Footer</footer>",
+            "</body></html>"
+        ]
+        .join("")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_extends_file() -> Result<(), failure::Error> {
+    assert_eq!(
+        tent::html_file!("tests/templates/index.tent", heading = "Index")?.to_string(),
+        [
+            "<html><head><title>Tent</title></head><body>",
+            "<header>Header</header>",
+            "<h1>Index</h1><p>Body</p>",
+            "<footer>Footer</footer>",
+            "</body></html>"
+        ]
+        .join("")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_extends_runtime() -> Result<(), failure::Error> {
+    let template = Template::load(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/templates/index.tent"
+    ))?;
+    let mut context = Context::new();
+    context.insert("heading", "Index");
+    assert_eq!(
+        template.render(&context)?.to_string(),
+        [
+            "<html><head><title>Tent</title></head><body>",
+            "<header>Header</header>",
+            "<h1>Index</h1><p>Body</p>",
+            "<footer>Footer</footer>",
+            "</body></html>"
+        ]
+        .join("")
+    );
+    Ok(())
+}
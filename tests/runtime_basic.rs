@@ -0,0 +1,209 @@
+use std::collections::BTreeMap;
+
+use tent::runtime::{Context, Error, Stylesheet, Template, Value};
+
+#[test]
+fn test_render_source() -> Result<(), failure::Error> {
+    let template = Template::from_source(
+        r#"
+        html
+            body
+                span.hello {greeting}
+                .hello {"Inner Text"}
+                a href={link.to_string()} "Link"
+    "#,
+    )?;
+    let mut context = Context::new();
+    context.insert("greeting", "HELLO!");
+    context.insert("link", "/home");
+    assert_eq!(
+        template.render(&context)?.to_string(),
+        [
+            "<html><body>",
+            "<span class=\"hello\">HELLO!</span>",
+            "<div class=\"hello\">Inner Text</div>",
+            "<a href=\"/home\">Link</a>",
+            "</body></html>"
+        ]
+        .join("")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_load_file() -> Result<(), failure::Error> {
+    let template = Template::load(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/templates/page.tent"
+    ))?;
+    let mut user = BTreeMap::new();
+    user.insert("name", "inkyu");
+    let mut context = Context::new();
+    context.insert("title", "Title");
+    context.insert("user", user);
+    assert_eq!(
+        template.render(&context)?.to_string(),
+        [
+            "<html><body>",
+            "<h1 class=\"title\">Title</h1>",
+            "<span data=\"inkyu\">HELLO!</span>",
+            "</body></html>"
+        ]
+        .join("")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_undefined_variable() -> Result<(), failure::Error> {
+    let template = Template::from_source("span {missing}")?;
+    match template.render(&Context::new()) {
+        Err(Error::UndefinedVariable(name)) => assert_eq!(name, "missing"),
+        etc => panic!("{:?}", etc),
+    }
+    Ok(())
+}
+
+#[test]
+fn test_reload_if_modified() -> Result<(), failure::Error> {
+    let path = std::env::temp_dir().join(format!("tent-reload-{}.tent", std::process::id()));
+    std::fs::write(&path, "span \"before\"")?;
+    let mut template = Template::load(&path)?;
+    assert!(!template.reload_if_modified()?);
+
+    std::fs::write(&path, "span \"after\"")?;
+    let file = std::fs::File::options().write(true).open(&path)?;
+    file.set_modified(std::time::SystemTime::now() + std::time::Duration::from_secs(1))?;
+    assert!(template.reload_if_modified()?);
+    assert_eq!(
+        template.render(&Context::new())?.to_string(),
+        "<span>after</span>"
+    );
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+fn test_stylesheet() -> Result<(), failure::Error> {
+    let stylesheet = Stylesheet::from_source(
+        r#"
+        .notice
+            width: {width}
+            .head
+                fontSize: "4em"
+    "#,
+    )?;
+    let mut context = Context::new();
+    context.insert("width", Value::from("400px"));
+    assert_eq!(
+        stylesheet.render(&context)?.to_string(),
+        ".notice .head {font-size: 4em;}.notice {width: 400px;}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_raw_text() -> Result<(), failure::Error> {
+    let template = Template::from_source(
+        r#"
+        script
+            let tab = "\t";
+            run();
+    "#,
+    )?;
+    assert_eq!(
+        template.render(&Context::new())?.to_string(),
+        "<script>let tab = \"\\t\";\nrun();</script>"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_unclosed_delimiter() -> Result<(), failure::Error> {
+    let error = Template::from_source("div\n    span {name\n    p \"Done\"").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "cannot parse template: line 2: unclosed delimiter"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_invalid_lines() -> Result<(), failure::Error> {
+    let error = |source: &str| Template::from_source(source).unwrap_err().to_string();
+    assert_eq!(
+        error("div\n    p = "),
+        "cannot parse template: line 2: unexpected `=`"
+    );
+    assert_eq!(
+        error("div\n    p -x \"a\""),
+        "cannot parse template: line 2: unexpected `-`"
+    );
+    assert_eq!(
+        error("div\n    p title="),
+        "cannot parse template: line 2: unfinished line"
+    );
+    assert_eq!(
+        error("div\n    @Card - \"a\""),
+        "cannot parse template: line 2: unexpected `-`"
+    );
+    assert_eq!(
+        error("div\n    \"Hi\"\n        span"),
+        "cannot parse template: line 3: text can't have indented contents"
+    );
+    assert_eq!(
+        error("div\nspan"),
+        "cannot parse template: a template must have a single root element"
+    );
+    let error = Template::load("tests/templates/nested_extends.tent").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "cannot parse template: `extends` must be the root of a template"
+    );
+    let error = Template::load("tests/templates/cycle_a.tent").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "cannot parse template: cyclic `extends`: cycle_a.tent -> cycle_b.tent -> cycle_a.tent"
+    );
+    let error = Stylesheet::from_source(".a\n    color: \"red\"\n        b").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "cannot parse template: line 3: a declaration can't have indented contents"
+    );
+    let error = Stylesheet::from_source(".a\n    color: \"red\"\nwidth: \"1px\"").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "cannot parse template: line 3: a declaration needs a selector"
+    );
+    let error = Stylesheet::from_source("width: \"1px\"").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "cannot parse template: line 1: a declaration needs a selector"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_unbalanced_delimiters() -> Result<(), failure::Error> {
+    let error = |source: &str| Template::from_source(source).unwrap_err().to_string();
+    assert_eq!(
+        error("div\n    span {name}}\n    p \"Done\""),
+        "cannot parse template: line 2: unexpected closing delimiter"
+    );
+    assert_eq!(
+        error("div\n    span {name\n    } \"a\")\n    p"),
+        "cannot parse template: line 3: unexpected closing delimiter"
+    );
+    assert_eq!(
+        error("div\n    span {name)\n    p"),
+        "cannot parse template: line 2: mismatched delimiters"
+    );
+    let template = Template::from_source("div\n    span title=\"a\" \")\"\n    p \"(\"")?;
+    assert_eq!(
+        template.render(&Context::new())?.to_string(),
+        "<div><span title=\"a\">)</span><p>(</p></div>"
+    );
+    assert!(Template::from_source("div\n    span {'}'}\n    p").is_ok());
+    assert!(Template::from_source("div\n    input r#type=\"text\"\n    p").is_ok());
+    Ok(())
+}
@@ -0,0 +1,83 @@
+#[test]
+fn test_scoped_css() -> Result<(), failure::Error> {
+    let styles = tent::css!(
+        scoped,
+        r#"
+        .card
+            width: "400px"
+            .title
+                fontSize: "2em"
+        body
+            margin: "0"
+    "#
+    );
+    let card = styles.class("card").to_string();
+    let title = styles.class("title").to_string();
+    assert!(card.starts_with("card-") && card.len() == "card-".len() + 6);
+    assert!(title.starts_with("title-"));
+    assert_eq!(styles.class("unknown"), "unknown");
+    assert_eq!(
+        styles.to_string(),
+        format!(
+            ".{} .{} {{font-size: 2em;}}.{} {{width: 400px;}}body {{margin: 0;}}",
+            card, title, card
+        )
+    );
+    Ok(())
+}
+
+#[test]
+fn test_scoped_template() -> Result<(), failure::Error> {
+    let styles = tent::css!(
+        scoped,
+        module,
+        r#"
+        .card
+            width: "400px"
+            .title
+                fontSize: "2em"
+    "#
+    );
+    assert_eq!(
+        tent::html!(
+            r#"
+            scoped styles
+            .card.{"global"}
+                h2.title "Title"
+        "#
+        )
+        .to_string(),
+        format!(
+            "<div class=\"{} global\"><h2 class=\"{}\">Title</h2></div>",
+            styles.card, styles.title
+        )
+    );
+    Ok(())
+}
+
+#[test]
+fn test_scopes_differ_by_call_site() -> Result<(), failure::Error> {
+    let first = tent::css!(scoped, ".card\n    width: \"1px\"");
+    let second = tent::css!(scoped, ".card\n    width: \"1px\"");
+    assert_ne!(first.class("card"), second.class("card"));
+    Ok(())
+}
+
+#[test]
+fn test_scoped_media() -> Result<(), failure::Error> {
+    let styles = tent::css!(
+        scoped,
+        r#"
+        @media "(max-width: 600.5px)"
+            .card
+                width: "100%"
+    "#
+    );
+    let card = styles.class("card").to_string();
+    assert!(card.starts_with("card-"));
+    assert_eq!(
+        styles.to_string(),
+        format!("@media (max-width: 600.5px) {{.{} {{width: 100%;}}}}", card)
+    );
+    Ok(())
+}
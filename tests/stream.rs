@@ -0,0 +1,88 @@
+#![cfg(feature = "async")]
+
+use futures::StreamExt;
+use tent::{AsyncHtmlContent, HtmlContent};
+
+async fn collect(content: AsyncHtmlContent, chunk_size: usize) -> Vec<String> {
+    content
+        .into_stream(chunk_size)
+        .map(|chunk| String::from_utf8(chunk.to_vec()).unwrap())
+        .collect()
+        .await
+}
+
+#[tokio::test]
+async fn test_fixed_size_chunks() -> Result<(), failure::Error> {
+    let content: HtmlContent = tent::html!(
+        r#"
+        p.notice "Hello, world"
+    "#
+    );
+    let chunks = collect(content.into(), 8).await;
+    assert_eq!(
+        chunks,
+        ["<p class", "=\"notice", "\">Hello,", " world</", "p>"]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deferred_content() -> Result<(), failure::Error> {
+    let head: HtmlContent = tent::html!(
+        r#"
+        head
+            title "Items"
+    "#
+    );
+    let items = async {
+        let names = ["a", "b"];
+        tent::html!(
+            r#"
+            ul
+                {HtmlContent::new_fragment(names.iter().map(|name| tent::html!("li {name}")).collect())}
+        "#
+        )
+    };
+    let page = AsyncHtmlContent::new_element(
+        "html".to_string(),
+        vec![],
+        vec![],
+        vec![
+            head.into(),
+            AsyncHtmlContent::new_element(
+                "body".to_string(),
+                vec![],
+                vec![],
+                vec![AsyncHtmlContent::new_deferred(items)],
+            ),
+        ],
+    );
+    let chunks = collect(page, 1024).await;
+    assert_eq!(
+        chunks,
+        [
+            "<html><head><title>Items</title></head><body>",
+            "<ul><li>a</li><li>b</li></ul></body></html>",
+        ]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_template_stream() -> Result<(), failure::Error> {
+    let page = tent::html_file!("tests/templates/index.tent", heading = "Index")?;
+    let comments = async { tent::html!("p \"Comments\"") };
+    let content =
+        AsyncHtmlContent::new_fragment(vec![page.into(), AsyncHtmlContent::new_deferred(comments)]);
+    let chunks = collect(content, 64).await;
+    assert_eq!(
+        chunks,
+        [
+            "<html><head><title>Tent</title></head><body><header>Header</head",
+            "er><h1>Index</h1><p>Body</p><footer>Footer</footer></body></html",
+            ">",
+            "<p>Comments</p>",
+        ]
+    );
+    Ok(())
+}
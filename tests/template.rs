@@ -0,0 +1,51 @@
+use tent::HtmlContent;
+
+struct Item {
+    name: &'static str,
+}
+
+#[tent::template("tests/templates/items.tent")]
+fn items_page(title: &str, items: &[Item]) -> HtmlContent {}
+
+fn label(item: &Item) -> String {
+    format!("<li>{}</li>", item.name)
+}
+
+#[tent::template("tests/templates/labels.tent")]
+fn labels(items: &[Item]) -> HtmlContent {}
+
+struct Profile {
+    name: &'static str,
+}
+
+impl Profile {
+    #[tent::template("tests/templates/profile.tent")]
+    fn render(&self, suffix: &str) -> HtmlContent {}
+}
+
+#[test]
+fn test_template_fn() -> Result<(), failure::Error> {
+    let items = [Item { name: "a" }, Item { name: "b" }];
+    assert_eq!(
+        items_page("List", &items).to_string(),
+        "<div><h1>List</h1><ul><li>a</li><li>b</li></ul></div>"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_template_fn_item() -> Result<(), failure::Error> {
+    let items = [Item { name: "a" }, Item { name: "b" }];
+    assert_eq!(labels(&items).to_string(), "<ul><li>a</li><li>b</li></ul>");
+    Ok(())
+}
+
+#[test]
+fn test_template_method() -> Result<(), failure::Error> {
+    let profile = Profile { name: "inkyu" };
+    assert_eq!(
+        profile.render("!").to_string(),
+        "<span class=\"name\">inkyu!</span>"
+    );
+    Ok(())
+}
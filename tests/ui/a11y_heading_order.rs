@@ -0,0 +1,10 @@
+fn main() {
+    let _ = tent::html!(
+        r#"
+        lint a11y(headingOrder)
+        section
+            h1 "Title"
+            h3 "Skipped"
+    "#
+    );
+}
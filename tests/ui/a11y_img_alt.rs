@@ -0,0 +1,8 @@
+fn main() {
+    let _ = tent::html!(
+        r#"
+        lint a11y(imgAlt)
+        img src="/logo.png"
+    "#
+    );
+}
@@ -0,0 +1,9 @@
+fn main() {
+    let _ = tent::html!(
+        r#"
+        lint a11y(inputLabel)
+        form
+            input id="name" type="text"
+    "#
+    );
+}
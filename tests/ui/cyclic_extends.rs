@@ -0,0 +1,4 @@
+fn main() {
+    // Relative to the crate trybuild builds the case in
+    let _ = tent::html_file!("../../../../tests/templates/cycle_a.tent");
+}
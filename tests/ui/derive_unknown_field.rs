@@ -0,0 +1,9 @@
+#[derive(tent::Template)]
+#[template(path = "../../../../tests/templates/titled.tent")]
+struct Page {
+    title: &'static str,
+}
+
+fn main() {
+    let _ = Page { title: "Title" };
+}
@@ -0,0 +1,11 @@
+fn main() {
+    let tr = std::collections::HashMap::<String, String>::new();
+    let _ = tent::html!(
+        r#"
+        translate tr
+        nav
+            a href="/" {t!("nav.home")}
+            p {t!("farewell")}
+    "#
+    );
+}
@@ -0,0 +1,8 @@
+fn main() {
+    let _ = tent::html!(
+        r#"
+        div
+            extends "layout.tent"
+    "#
+    );
+}
@@ -0,0 +1,12 @@
+fn main() {
+    let tr = std::collections::HashMap::<String, String>::new();
+    let name = "Inkyu";
+    let _ = tent::html!(
+        r#"
+        translate tr
+        nav
+            a href="/" {t!("nav.home")}
+            p {t!("greeting", name = name)}
+    "#
+    );
+}
@@ -0,0 +1,9 @@
+fn main() {
+    let styles = tent::css!(scoped, module, ".card\n    width: \"1px\"");
+    let _ = tent::html!(
+        r#"
+        scoped styles
+        .card.missing "Hi"
+    "#
+    );
+}
@@ -0,0 +1,6 @@
+#[tent::template("../../../../tests/templates/titled.tent")]
+fn page(title: &str) -> tent::HtmlContent {}
+
+fn main() {
+    let _ = page("Title");
+}
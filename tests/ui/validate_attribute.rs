@@ -0,0 +1,7 @@
+fn main() {
+    let _ = tent::html!(
+        r#"
+        a hreff="/" "Home"
+    "#
+    );
+}
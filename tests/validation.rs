@@ -0,0 +1,58 @@
+#[test]
+fn test_custom_attributes() -> Result<(), failure::Error> {
+    assert_eq!(
+        tent::html!(
+            r#"
+            div dataId="1" ariaLabel="Label" hxGet="/items"
+                input type="text" placeholder="Name" onchange="go()"
+        "#
+        )
+        .to_string(),
+        [
+            "<div data-id=\"1\" aria-label=\"Label\" hx-get=\"/items\">",
+            "<input type=\"text\" placeholder=\"Name\" onchange=\"go()\"></input>",
+            "</div>"
+        ]
+        .join("")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_svg_elements() -> Result<(), failure::Error> {
+    assert_eq!(
+        tent::html!(
+            r#"
+            svg viewBox="0 0 1 1"
+                linearGradient id="g"
+                foreignObject
+                    p "Text"
+        "#
+        )
+        .to_string(),
+        [
+            "<svg viewBox=\"0 0 1 1\">",
+            "<linearGradient id=\"g\"></linearGradient>",
+            "<foreignObject><p>Text</p></foreignObject>",
+            "</svg>"
+        ]
+        .join("")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_custom_elements() -> Result<(), failure::Error> {
+    assert_eq!(
+        tent::html!(
+            r#"
+            div
+                my-widget dataId="1"
+                    user-card "Card"
+        "#
+        )
+        .to_string(),
+        "<div><my-widget data-id=\"1\"><user-card>Card</user-card></my-widget></div>"
+    );
+    Ok(())
+}
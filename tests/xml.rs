@@ -0,0 +1,147 @@
+#[test]
+fn test_sitemap() -> Result<(), failure::Error> {
+    let pages = ["/", "/about?a=1&b=2"];
+    let urls = pages
+        .iter()
+        .map(|page| {
+            tent::xml!(
+                r#"
+                url
+                    loc {page}
+                    changefreq "weekly"
+            "#
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        tent::xml!(
+            r#"
+            ?xml version="1.0" encoding="UTF-8"
+            urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9"
+                {urls}
+        "#
+        )
+        .to_string(),
+        [
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>",
+            "<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">",
+            "<url><loc>/</loc><changefreq>weekly</changefreq></url>",
+            "<url><loc>/about?a=1&amp;b=2</loc><changefreq>weekly</changefreq></url>",
+            "</urlset>",
+        ]
+        .join("")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_namespaces() -> Result<(), failure::Error> {
+    let user = "a\"b'c";
+    assert_eq!(
+        tent::xml!(
+            r#"
+            soap:Envelope xmlns:soap="http://www.w3.org/2003/05/soap-envelope"
+                soap:Body
+                    getUser userId={user} includeDeleted="false"
+        "#
+        )
+        .to_string(),
+        [
+            "<soap:Envelope xmlns:soap=\"http://www.w3.org/2003/05/soap-envelope\">",
+            "<soap:Body>",
+            "<getUser userId=\"a&quot;b&apos;c\" includeDeleted=\"false\"/>",
+            "</soap:Body>",
+            "</soap:Envelope>",
+        ]
+        .join("")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_cdata() -> Result<(), failure::Error> {
+    let script = "if (a < b && c]]>d) {}";
+    assert_eq!(
+        tent::xml!(
+            r#"
+            config
+                script
+                    !cdata {script}
+                note "1 < 2"
+        "#
+        )
+        .to_string(),
+        [
+            "<config>",
+            "<script><![CDATA[if (a < b && c]]]]><![CDATA[>d) {}]]></script>",
+            "<note>1 &lt; 2</note>",
+            "</config>",
+        ]
+        .join("")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_directive_names() -> Result<(), failure::Error> {
+    assert_eq!(
+        tent::xml!(
+            r#"
+            document
+                translate lang="fr"
+                scoped "yes"
+                lint
+                block name="intro"
+                extends "base"
+        "#
+        )
+        .to_string(),
+        [
+            "<document>",
+            "<translate lang=\"fr\"/>",
+            "<scoped>yes</scoped>",
+            "<lint/>",
+            "<block name=\"intro\"/>",
+            "<extends>base</extends>",
+            "</document>",
+        ]
+        .join("")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dashed_names() -> Result<(), failure::Error> {
+    assert_eq!(
+        tent::xml!(
+            r#"
+            web-app version="4.0"
+                servlet
+                    servlet-name load-on-startup="1" "main"
+        "#
+        )
+        .to_string(),
+        [
+            "<web-app version=\"4.0\">",
+            "<servlet><servlet-name load-on-startup=\"1\">main</servlet-name></servlet>",
+            "</web-app>",
+        ]
+        .join("")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_escaped_quoted_names() -> Result<(), failure::Error> {
+    assert_eq!(
+        tent::xml!(
+            r#"
+            item "a\"b"="1"
+        "#
+        )
+        .to_string(),
+        // Kept exactly as written, quotes included
+        "<item \"a\\\"b\"=\"1\"/>",
+    );
+    Ok(())
+}